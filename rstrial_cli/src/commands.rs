@@ -1,9 +1,10 @@
 use clap::{Parser, Subcommand};
 
-use self::{check::CheckArgs, convert::ConvertArgs};
+use self::{check::CheckArgs, convert::ConvertArgs, import::ImportArgs};
 
 pub mod check;
 pub mod convert;
+pub mod import;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -19,6 +20,8 @@ pub enum Commands {
     Convert(ConvertArgs),
     /// Check the manuscript and give advice
     Check(CheckArgs),
+    /// Import a manuscript written in other formats into rstrial markdown
+    Import(ImportArgs),
 }
 
 pub trait Command {
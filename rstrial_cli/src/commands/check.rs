@@ -3,7 +3,10 @@ use std::{path::Path, thread};
 use llm_chain::{executor, parameters, prompt};
 use rstrial_converter::converter::{vfm::line_converter::VfmLineConverter, LineConverter};
 use rstrial_parser::{
-    tokens::section::{Document, Section},
+    tokens::{
+        section::{Document, Section},
+        Line, LineItem,
+    },
     ManuscriptParser,
 };
 
@@ -22,6 +25,10 @@ pub struct CheckArgs {
     /// Currentry not implemented
     #[arg(long)]
     use_llm: bool,
+
+    /// List comments and author-only regions instead of checking the manuscript
+    #[arg(long)]
+    comments: bool,
 }
 
 pub struct CheckCommand;
@@ -29,12 +36,21 @@ impl Command for CheckCommand {
     type Args = CheckArgs;
     fn execute(&self, args: &Self::Args) -> Result<(), Box<dyn std::error::Error>> {
         let tokens = Self::tokenize(args.target.as_path())?;
+        if args.comments {
+            Self::list_comments(tokens);
+            return Ok(());
+        }
         let sections: Vec<(Document, String)> = tokens
             .into_iter()
             .filter_map(|t| match t {
                 Section::Title(_title) => None,
+                Section::Scene(doc, _) if doc.is_skipped() => None,
                 Section::Scene(doc, body) => {
-                    let body: String = body.into_iter().map(VfmLineConverter::convert).collect();
+                    let converter = VfmLineConverter::default();
+                    let body: String = body
+                        .into_iter()
+                        .map(|line| converter.convert(line))
+                        .collect();
                     Some((doc, body))
                 }
             })
@@ -70,6 +86,41 @@ impl CheckCommand {
         Ok(tokens)
     }
 
+    fn list_comments(tokens: Vec<Section>) {
+        for token in tokens {
+            if let Section::Scene(document, body) = token {
+                let comments: Vec<String> = body.iter().flat_map(Self::comments).collect();
+                if comments.is_empty() {
+                    continue;
+                }
+                println!("## {}\n", document.title);
+                for comment in comments {
+                    println!("{}", comment);
+                }
+                println!();
+            }
+        }
+    }
+
+    fn comments(line: &Line) -> Vec<String> {
+        match line {
+            Line::Paragraph(items) | Line::Conversation(items) | Line::Quotation(items) => items
+                .iter()
+                .filter_map(|item| match item {
+                    LineItem::Comment(comment) => Some(format!("- {{#{}}}", comment)),
+                    _ => None,
+                })
+                .collect(),
+            Line::Comment(comment) => vec![format!("- //{}", comment)],
+            Line::BlockComment(comment) => vec![format!("- /*{}*/", comment)],
+            Line::AuthorOnly(lines) => {
+                let mut comments = vec![format!("- :::author ({} lines)", lines.len())];
+                comments.extend(lines.iter().flat_map(Self::comments));
+                comments
+            }
+        }
+    }
+
     async fn check_scenes(scenes: Vec<(Document, String)>) {
         let handles: Vec<_> = scenes
             .into_iter()
@@ -81,14 +132,19 @@ impl CheckCommand {
         while let Some(r) = results.next().await {
             res.push(r.await);
         }
-        res.sort_by(|(i1, _), (i2, _)| i1.cmp(i2));
+        res.sort_by_key(|(index, _)| *index);
         for (_, r) in res {
             println!("{}", r);
         }
     }
 
     async fn check_scene(index: usize, document: Document, body: String) -> (usize, String) {
-        let tags = document.tags.join(", ");
+        let tags = document
+            .tags
+            .iter()
+            .map(|tag| tag.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
         let exec: llm_chain_openai::chatgpt::Executor = executor!().unwrap();
         let temprate = r#"
             あなたは自動化された小説制作支援システムです。以下の文章は小説の一シーンです。以下の書式に沿って著者への助言を行ってください。
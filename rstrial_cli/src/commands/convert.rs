@@ -1,7 +1,8 @@
 use std::{
     fmt::{Display, Formatter},
-    fs,
-    path::Path,
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
 };
 
 use clap::ValueEnum;
@@ -9,10 +10,30 @@ use common_path::common_path;
 use log::{info, warn};
 use rstrial_converter::converter::{
     aozora::manuscript_converter::AozoraManuscriptConverter,
-    vfm::manuscript_converter::VfmManuscriptConverter, ManuscriptConverter,
+    asciidoc::manuscript_converter::AsciidocManuscriptConverter,
+    daihon::manuscript_converter::DaihonManuscriptConverter,
+    docx::manuscript_converter::DocxManuscriptConverter,
+    epub::manuscript_converter::EpubManuscriptConverter,
+    fountain::manuscript_converter::FountainManuscriptConverter,
+    html::manuscript_converter::HtmlManuscriptConverter,
+    indesign::manuscript_converter::IndesignManuscriptConverter,
+    kakuyomu::manuscript_converter::KakuyomuManuscriptConverter,
+    latex::manuscript_converter::LatexManuscriptConverter,
+    narou::manuscript_converter::NarouManuscriptConverter,
+    odt::manuscript_converter::OdtManuscriptConverter,
+    pixiv::manuscript_converter::PixivManuscriptConverter,
+    plain::manuscript_converter::PlainManuscriptConverter,
+    review::{catalog::Catalog, manuscript_converter::ReviewManuscriptConverter},
+    ssml::manuscript_converter::SsmlManuscriptConverter,
+    template::{manuscript_converter::TemplateManuscriptConverter, snippets::Snippets},
+    typst::manuscript_converter::TypstManuscriptConverter,
+    vfm::manuscript_converter::VfmManuscriptConverter,
+    CommentPolicy, ConverterOptions, ManuscriptConverter, PageGrid, RubyPolicy, SesamePolicy,
+    WritingMode,
 };
+use rstrial_parser::{tokens::section::Section, AsciidocParser, ManuscriptParser};
 
-use crate::PathManuscriptTuple;
+use crate::config::{load_template, Config};
 
 use super::Command;
 
@@ -30,6 +51,23 @@ pub struct ConvertArgs {
     /// Output format
     /// vfm: Vivliostyle Flavored Markdown
     /// aozora: Aozora Bunko format
+    /// asciidoc: AsciiDoc for Asciidoctor
+    /// daihon: Japanese audio-drama script with speakers from @characters
+    /// docx: Word document with vertical writing (requires --output)
+    /// epub: EPUB 3 with vertical writing (requires --output)
+    /// fountain: Fountain screenplay with speakers from @characters
+    /// html: Standalone HTML
+    /// indesign: InDesign Tagged Text (requires --output)
+    /// kakuyomu: Episode text for Kakuyomu
+    /// latex: LaTeX document for upLaTeX or LuaLaTeX
+    /// narou: Episode text for Shosetsuka ni Naro
+    /// odt: OpenDocument Text with vertical writing (requires --output)
+    /// pixiv: pixiv novel
+    /// plain: Plain text without markup
+    /// review: Re:VIEW chapters, with catalog.yml when --output is a directory
+    /// ssml: SSML for text-to-speech engines, reading ruby aloud
+    /// template: Snippets of the template file given by --template
+    /// typst: Typst document
     #[arg(short, long)]
     format: OutputFormat,
 
@@ -37,12 +75,87 @@ pub struct ConvertArgs {
     /// If not specified, output to stdout
     #[arg(short, long)]
     output: Option<std::path::PathBuf>,
+
+    /// Configuration file path
+    /// Options in the `[convert]` table are overridden by flags
+    #[arg(short, long)]
+    config: Option<std::path::PathBuf>,
+
+    /// Line put between scenes
+    ///
+    /// default: †
+    #[arg(long)]
+    scene_separator: Option<String>,
+
+    /// Comment handling
+    /// drop: Drop comments
+    /// keep: Render comments in the notation of the output format
+    #[arg(long)]
+    comments: Option<CommentPolicy>,
+
+    /// Ruby handling
+    /// keep: Render ruby in the notation of the output format
+    /// strip: Render the base text only
+    /// parenthesize: Render the ruby in parentheses after the base text
+    /// reading: Render the ruby only
+    #[arg(long)]
+    ruby: Option<RubyPolicy>,
+
+    /// Sesame handling
+    /// keep: Render emphasis in the notation of the output format
+    /// strip: Render the text only
+    /// bracket: Render the text in angle brackets
+    #[arg(long)]
+    sesame: Option<SesamePolicy>,
+
+    /// Characters put at the beginning of paragraphs
+    ///
+    /// default: full-width space
+    #[arg(long)]
+    indent: Option<String>,
+
+    /// Text direction of formats supporting both
+    /// vertical: Top to bottom, lines from right to left
+    /// horizontal: Left to right, lines from top to bottom
+    #[arg(long)]
+    writing_mode: Option<WritingMode>,
+
+    /// Characters per line and lines per page of page-oriented formats such as docx and odt
+    ///
+    /// default: 40x30
+    #[arg(long)]
+    page_grid: Option<PageGrid>,
+
+    /// Voice name of dialogue lines in speech formats such as ssml
+    #[arg(long)]
+    dialogue_voice: Option<String>,
+
+    /// Template file of the template format
+    #[arg(long)]
+    template: Option<std::path::PathBuf>,
 }
 
 #[derive(Debug, Clone)]
 enum OutputFormat {
     Vfm,
     Aozora,
+    Asciidoc,
+    Daihon,
+    Docx,
+    Epub,
+    Fountain,
+    Html,
+    Indesign,
+    Kakuyomu,
+    Latex,
+    Narou,
+    Odt,
+    Pixiv,
+    Plain,
+    Review,
+    Ssml,
+    Template,
+    Typst,
 }
 
 #[derive(Debug, Clone)]
@@ -54,13 +167,78 @@ enum AvailableExt {
 
 impl ValueEnum for OutputFormat {
     fn value_variants<'a>() -> &'a [Self] {
-        &[OutputFormat::Vfm, OutputFormat::Aozora]
+        &[
+            OutputFormat::Vfm,
+            OutputFormat::Aozora,
+            OutputFormat::Asciidoc,
+            OutputFormat::Daihon,
+            OutputFormat::Docx,
+            OutputFormat::Epub,
+            OutputFormat::Fountain,
+            OutputFormat::Html,
+            OutputFormat::Indesign,
+            OutputFormat::Kakuyomu,
+            OutputFormat::Latex,
+            OutputFormat::Narou,
+            OutputFormat::Odt,
+            OutputFormat::Pixiv,
+            OutputFormat::Plain,
+            OutputFormat::Review,
+            OutputFormat::Ssml,
+            OutputFormat::Template,
+            OutputFormat::Typst,
+        ]
     }
 
     fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
         match self {
             OutputFormat::Vfm => Some(clap::builder::PossibleValue::new("vfm")),
             OutputFormat::Aozora => Some(clap::builder::PossibleValue::new("aozora")),
+            OutputFormat::Asciidoc => Some(clap::builder::PossibleValue::new("asciidoc")),
+            OutputFormat::Daihon => Some(clap::builder::PossibleValue::new("daihon")),
+            OutputFormat::Docx => Some(clap::builder::PossibleValue::new("docx")),
+            OutputFormat::Epub => Some(clap::builder::PossibleValue::new("epub")),
+            OutputFormat::Fountain => Some(clap::builder::PossibleValue::new("fountain")),
+            OutputFormat::Html => Some(clap::builder::PossibleValue::new("html")),
+            OutputFormat::Indesign => Some(clap::builder::PossibleValue::new("indesign")),
+            OutputFormat::Kakuyomu => Some(clap::builder::PossibleValue::new("kakuyomu")),
+            OutputFormat::Latex => Some(clap::builder::PossibleValue::new("latex")),
+            OutputFormat::Narou => Some(clap::builder::PossibleValue::new("narou")),
+            OutputFormat::Odt => Some(clap::builder::PossibleValue::new("odt")),
+            OutputFormat::Pixiv => Some(clap::builder::PossibleValue::new("pixiv")),
+            OutputFormat::Plain => Some(clap::builder::PossibleValue::new("plain")),
+            OutputFormat::Review => Some(clap::builder::PossibleValue::new("review")),
+            OutputFormat::Ssml => Some(clap::builder::PossibleValue::new("ssml")),
+            OutputFormat::Template => Some(clap::builder::PossibleValue::new("template")),
+            OutputFormat::Typst => Some(clap::builder::PossibleValue::new("typst")),
+        }
+    }
+}
+
+impl OutputFormat {
+    // Archives and UTF-16 text cannot be concatenated nor mixed with the progress bar on stdout.
+    fn is_binary(&self) -> bool {
+        matches!(
+            self,
+            OutputFormat::Docx | OutputFormat::Epub | OutputFormat::Indesign | OutputFormat::Odt
+        )
+    }
+
+    // Extension of output files. Plain text formats keep the extension of the source.
+    fn extension(&self) -> Option<&'static str> {
+        match self {
+            OutputFormat::Asciidoc => Some("adoc"),
+            OutputFormat::Docx => Some("docx"),
+            OutputFormat::Epub => Some("epub"),
+            OutputFormat::Fountain => Some("fountain"),
+            OutputFormat::Html => Some("html"),
+            OutputFormat::Indesign => Some("txt"),
+            OutputFormat::Latex => Some("tex"),
+            OutputFormat::Odt => Some("odt"),
+            OutputFormat::Review => Some("re"),
+            OutputFormat::Ssml => Some("ssml"),
+            OutputFormat::Typst => Some("typ"),
+            _ => None,
         }
     }
 }
@@ -95,19 +273,100 @@ impl Command for ConvertCommand {
     type Args = ConvertArgs;
 
     fn execute(&self, args: &Self::Args) -> Result<(), Box<dyn std::error::Error>> {
-        let manuscripts = Self::extract_manuscripts(args);
-        let manuscripts = Self::convert_manuscripts(args, manuscripts);
-        Self::output(args, manuscripts);
+        let options = Self::options(args)?;
+        let snippets = Self::snippets(args)?;
+        let paths = Self::extract_paths(args);
+        if args.format.is_binary() {
+            match &args.output {
+                None => return Err("this format requires --output".into()),
+                Some(output) if !output.is_dir() && paths.len() > 1 => return Err(
+                    "converting multiple manuscripts into this format requires an output directory"
+                        .into(),
+                ),
+                _ => {}
+            }
+        }
+        Self::convert_manuscripts(args, options, snippets, paths)?;
         Ok(())
     }
 }
 
 impl ConvertCommand {
-    fn extract_manuscripts(args: &ConvertArgs) -> Vec<PathManuscriptTuple> {
+    fn options(args: &ConvertArgs) -> Result<ConverterOptions, Box<dyn std::error::Error>> {
+        let config = match &args.config {
+            Some(path) => Config::load(path)?.convert,
+            None => Default::default(),
+        };
+        let mut options = ConverterOptions::default();
+        if let Some(scene_separator) = args.scene_separator.clone().or(config.scene_separator) {
+            options.scene_separator = scene_separator;
+        }
+        match args.comments {
+            Some(comments) => options.comments = comments,
+            None => {
+                if let Some(comments) = config.comments {
+                    options.comments = comments.parse()?;
+                }
+            }
+        }
+        match args.ruby {
+            Some(ruby) => options.ruby = ruby,
+            None => {
+                if let Some(ruby) = config.ruby {
+                    options.ruby = ruby.parse()?;
+                }
+            }
+        }
+        match args.sesame {
+            Some(sesame) => options.sesame = sesame,
+            None => {
+                if let Some(sesame) = config.sesame {
+                    options.sesame = sesame.parse()?;
+                }
+            }
+        }
+        if let Some(indent) = args.indent.clone().or(config.indent) {
+            options.indent = indent;
+        }
+        match args.writing_mode {
+            Some(writing_mode) => options.writing_mode = writing_mode,
+            None => {
+                if let Some(writing_mode) = config.writing_mode {
+                    options.writing_mode = writing_mode.parse()?;
+                }
+            }
+        }
+        match args.page_grid {
+            Some(page_grid) => options.page_grid = page_grid,
+            None => {
+                if let Some(page_grid) = config.page_grid {
+                    options.page_grid = page_grid.parse()?;
+                }
+            }
+        }
+        if let Some(dialogue_voice) = args.dialogue_voice.clone().or(config.dialogue_voice) {
+            options.dialogue_voice = Some(dialogue_voice);
+        }
+        Ok(options)
+    }
+
+    fn snippets(args: &ConvertArgs) -> Result<Snippets, Box<dyn std::error::Error>> {
+        match (&args.format, &args.template) {
+            (OutputFormat::Template, None) => Err("the template format requires --template".into()),
+            (OutputFormat::Template, Some(path)) => load_template(path),
+            _ => Ok(Snippets::default()),
+        }
+    }
+
+    fn extract_paths(args: &ConvertArgs) -> Vec<PathBuf> {
         match args.target.is_dir() {
             true => {
-                let mut manuscripts = vec![];
-                for entry in walkdir::WalkDir::new(args.target.clone()).into_iter() {
+                let mut paths = vec![];
+                // Sorted so that manuscripts keep the order of their file names.
+                for entry in walkdir::WalkDir::new(args.target.clone())
+                    .sort_by_file_name()
+                    .into_iter()
+                {
                     match entry {
                         Ok(entry) => {
                             if entry.file_type().is_dir() || entry.file_type().is_symlink() {
@@ -129,9 +388,7 @@ impl ConvertCommand {
                                 continue;
                             }
 
-                            let content = fs::read_to_string(path)
-                                .expect("Should have been able to read the file");
-                            manuscripts.push((path.to_string_lossy().to_string(), content));
+                            paths.push(path.to_path_buf());
                         }
                         Err(err) => {
                             warn!("Error: {:?}", err);
@@ -139,77 +396,190 @@ impl ConvertCommand {
                         }
                     }
                 }
-                manuscripts
-            }
-            false => {
-                let path = args.target.as_path();
-                let content = fs::read_to_string(args.target.clone())
-                    .expect("Should have been able to read the file");
-                vec![(path.to_string_lossy().to_string(), content)]
+                paths
             }
+            false => vec![args.target.clone()],
         }
     }
 
+    /// Converts manuscripts one by one, writing each straight into its destination.
     fn convert_manuscripts(
         args: &ConvertArgs,
-        manuscripts: Vec<PathManuscriptTuple>,
-    ) -> Vec<PathManuscriptTuple> {
+        options: ConverterOptions,
+        snippets: Snippets,
+        paths: Vec<PathBuf>,
+    ) -> io::Result<()> {
         let mut bar = progress::Bar::new();
         bar.set_job_title("Converting");
-        let bar_tick = 100 / manuscripts.len() as u64;
-        manuscripts
-            .iter()
-            .map(|(path, text)| {
-                let path = Path::new(path);
-                bar.add_percent(bar_tick as i32);
-                let parser = rstrial_parser::ManuscriptParser::new(text);
-                let tokens = parser.collect();
-
-                let path = path.to_string_lossy().to_string();
-                match args.format {
-                    OutputFormat::Vfm => (path, VfmManuscriptConverter::convert(tokens)),
-                    OutputFormat::Aozora => (path, AozoraManuscriptConverter::convert(tokens)),
-                }
-            })
-            .collect::<Vec<PathManuscriptTuple>>()
-    }
-
-    fn output(args: &ConvertArgs, manuscripts: Vec<PathManuscriptTuple>) {
-        match &args.output {
-            Some(path) => match path.is_dir() {
-                true => {
-                    for (p, text) in manuscripts.iter() {
-                        let output_path = path.canonicalize().unwrap();
-                        let ext_path = Path::new(p).canonicalize().unwrap();
-                        let common_prefix = common_path(&output_path, &ext_path)
-                            .expect("Unable to get common path");
-                        let relative_path = ext_path
-                            .strip_prefix(common_prefix.to_str().unwrap())
-                            .expect("Unable to get relative path");
-                        let target_path = output_path.join(relative_path);
-                        let target_dir_path = target_path.parent().unwrap();
-                        info!(
-                            "Saving: {} -> {}",
-                            ext_path.display(),
-                            target_path.display()
-                        );
-                        fs::create_dir_all(target_dir_path).expect("Unable to create directory");
-                        fs::write(target_path, text).expect("Unable to write file");
+        let bar_tick = 100 / paths.len().max(1) as u64;
+        let mut single_file = match &args.output {
+            Some(path) if !path.is_dir() => Some(BufWriter::new(File::create(path)?)),
+            _ => None,
+        };
+        let mut catalog = Catalog::default();
+        for (index, path) in paths.iter().enumerate() {
+            bar.add_percent(bar_tick as i32);
+            let text = fs::read_to_string(path)?;
+            let sections = Self::parse(path, &text, &options);
+            match (&args.output, single_file.as_mut()) {
+                (_, Some(writer)) => {
+                    if index > 0 {
+                        writeln!(writer)?;
                     }
+                    Self::write_manuscript(args, &options, &snippets, sections, writer)?;
                 }
-                false => {
-                    let manuscripts = manuscripts
-                        .iter()
-                        .map(|(_, text)| text.to_owned())
-                        .collect::<Vec<String>>();
-                    fs::write(path, manuscripts.join("\n")).expect("Unable to write file");
+                (Some(output), None) => {
+                    let mut target_path = Self::target_path(output, path);
+                    let extension = match args.format {
+                        OutputFormat::Template => snippets.extension.as_deref(),
+                        _ => args.format.extension(),
+                    };
+                    if let Some(extension) = extension {
+                        target_path.set_extension(extension);
+                    }
+                    info!("Saving: {} -> {}", path.display(), target_path.display());
+                    fs::create_dir_all(target_path.parent().unwrap())?;
+                    let mut writer = BufWriter::new(File::create(&target_path)?);
+                    Self::write_manuscript(args, &options, &snippets, sections, &mut writer)?;
+                    writer.flush()?;
+                    catalog
+                        .chapters
+                        .push(Self::catalog_entry(output, &target_path)?);
                 }
-            },
-            None => {
-                for (_, text) in manuscripts {
-                    println!("{}\n\n----\n\n", text);
+                (None, None) => {
+                    let mut writer = io::stdout().lock();
+                    Self::write_manuscript(args, &options, &snippets, sections, &mut writer)?;
+                    write!(writer, "\n\n----\n\n\n")?;
                 }
             }
         }
+        if let Some(mut writer) = single_file {
+            writer.flush()?;
+        }
+        if let (OutputFormat::Review, Some(output)) = (&args.format, &args.output) {
+            if output.is_dir() {
+                let catalog_path = output.join("catalog.yml");
+                info!("Saving: {}", catalog_path.display());
+                let mut writer = BufWriter::new(File::create(catalog_path)?);
+                catalog.write(&mut writer)?;
+                writer.flush()?;
+            }
+        }
+        Ok(())
+    }
+
+    // Re:VIEW expects paths in `catalog.yml` relative to the project root with `/` as the separator.
+    fn catalog_entry(output: &Path, target_path: &Path) -> io::Result<String> {
+        let output_path = output.canonicalize()?;
+        let relative_path = target_path.strip_prefix(&output_path).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "{} is not in the output directory {}",
+                    target_path.display(),
+                    output_path.display()
+                ),
+            )
+        })?;
+        Ok(relative_path
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/"))
+    }
+
+    // AsciiDoc sources are read as AsciiDoc and the others as rstrial manuscripts.
+    fn parse<'a>(
+        path: &Path,
+        text: &'a str,
+        options: &ConverterOptions,
+    ) -> Box<dyn Iterator<Item = Section> + 'a> {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("adoc") => {
+                Box::new(AsciidocParser::new(text).with_scene_separator(&options.scene_separator))
+            }
+            _ => Box::new(ManuscriptParser::new(text)),
+        }
+    }
+
+    fn write_manuscript(
+        args: &ConvertArgs,
+        options: &ConverterOptions,
+        snippets: &Snippets,
+        sections: impl IntoIterator<Item = Section>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        match args.format {
+            OutputFormat::Vfm => {
+                VfmManuscriptConverter::new(options.clone()).write(sections, writer)
+            }
+            OutputFormat::Aozora => {
+                AozoraManuscriptConverter::new(options.clone()).write(sections, writer)
+            }
+            OutputFormat::Asciidoc => {
+                AsciidocManuscriptConverter::new(options.clone()).write(sections, writer)
+            }
+            OutputFormat::Daihon => {
+                DaihonManuscriptConverter::new(options.clone()).write(sections, writer)
+            }
+            OutputFormat::Docx => {
+                DocxManuscriptConverter::new(options.clone()).write(sections, writer)
+            }
+            OutputFormat::Epub => {
+                EpubManuscriptConverter::new(options.clone()).write(sections, writer)
+            }
+            OutputFormat::Fountain => {
+                FountainManuscriptConverter::new(options.clone()).write(sections, writer)
+            }
+            OutputFormat::Html => {
+                HtmlManuscriptConverter::new(options.clone()).write(sections, writer)
+            }
+            OutputFormat::Indesign => {
+                IndesignManuscriptConverter::new(options.clone()).write_utf16(sections, writer)
+            }
+            OutputFormat::Kakuyomu => {
+                KakuyomuManuscriptConverter::new(options.clone()).write(sections, writer)
+            }
+            OutputFormat::Latex => {
+                LatexManuscriptConverter::new(options.clone()).write(sections, writer)
+            }
+            OutputFormat::Narou => {
+                NarouManuscriptConverter::new(options.clone()).write(sections, writer)
+            }
+            OutputFormat::Odt => {
+                OdtManuscriptConverter::new(options.clone()).write(sections, writer)
+            }
+            OutputFormat::Pixiv => {
+                PixivManuscriptConverter::new(options.clone()).write(sections, writer)
+            }
+            OutputFormat::Plain => {
+                PlainManuscriptConverter::new(options.clone()).write(sections, writer)
+            }
+            OutputFormat::Review => {
+                ReviewManuscriptConverter::new(options.clone()).write(sections, writer)
+            }
+            OutputFormat::Ssml => {
+                SsmlManuscriptConverter::new(options.clone()).write(sections, writer)
+            }
+            OutputFormat::Template => {
+                TemplateManuscriptConverter::new(options.clone(), snippets.clone())
+                    .write(sections, writer)
+            }
+            OutputFormat::Typst => {
+                TypstManuscriptConverter::new(options.clone()).write(sections, writer)
+            }
+        }
+    }
+
+    // Mirrors the path of a manuscript relative to the common prefix into the output directory.
+    fn target_path(output: &Path, path: &Path) -> PathBuf {
+        let output_path = output.canonicalize().unwrap();
+        let ext_path = path.canonicalize().unwrap();
+        let common_prefix =
+            common_path(&output_path, &ext_path).expect("Unable to get common path");
+        let relative_path = ext_path
+            .strip_prefix(common_prefix.to_str().unwrap())
+            .expect("Unable to get relative path");
+        output_path.join(relative_path)
     }
 }
@@ -0,0 +1,82 @@
+use std::fs;
+
+use clap::ValueEnum;
+use log::info;
+use rstrial_converter::converter::{
+    rstrial::manuscript_converter::RstrialManuscriptConverter, ManuscriptConverter,
+};
+use rstrial_parser::{tokens::section::Section, AozoraParser, Platform, VfmParser, WebNovelParser};
+
+use super::Command;
+
+#[derive(Debug, clap::Args)]
+pub struct ImportArgs {
+    /// Target file path
+    target: std::path::PathBuf,
+
+    /// Input format
+    /// aozora: Aozora Bunko format
+    /// kakuyomu: Kakuyomu notation
+    /// narou: Shosetsuka ni Naro notation
+    /// vfm: Vivliostyle Flavored Markdown
+    #[arg(long)]
+    from: InputFormat,
+
+    /// Output file path
+    /// If not specified, output to stdout
+    #[arg(short, long)]
+    output: Option<std::path::PathBuf>,
+}
+
+#[derive(Debug, Clone)]
+enum InputFormat {
+    Aozora,
+    Kakuyomu,
+    Narou,
+    Vfm,
+}
+
+impl ValueEnum for InputFormat {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            InputFormat::Aozora,
+            InputFormat::Kakuyomu,
+            InputFormat::Narou,
+            InputFormat::Vfm,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+        match self {
+            InputFormat::Aozora => Some(clap::builder::PossibleValue::new("aozora")),
+            InputFormat::Kakuyomu => Some(clap::builder::PossibleValue::new("kakuyomu")),
+            InputFormat::Narou => Some(clap::builder::PossibleValue::new("narou")),
+            InputFormat::Vfm => Some(clap::builder::PossibleValue::new("vfm")),
+        }
+    }
+}
+
+pub struct ImportCommand;
+
+impl Command for ImportCommand {
+    type Args = ImportArgs;
+
+    fn execute(&self, args: &Self::Args) -> Result<(), Box<dyn std::error::Error>> {
+        let content = fs::read_to_string(&args.target)?;
+        let sections: Vec<Section> = match args.from {
+            InputFormat::Aozora => AozoraParser::new(&content).collect(),
+            InputFormat::Kakuyomu => WebNovelParser::new(&content, Platform::Kakuyomu).collect(),
+            InputFormat::Narou => WebNovelParser::new(&content, Platform::Narou).collect(),
+            InputFormat::Vfm => VfmParser::new(&content).collect(),
+        };
+        let manuscript = RstrialManuscriptConverter::default().convert(sections);
+        match &args.output {
+            Some(path) => {
+                info!("Saving: {} -> {}", args.target.display(), path.display());
+                fs::write(path, manuscript)?;
+            }
+            None => print!("{}", manuscript),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use std::path::Path;
+
+use rstrial_converter::converter::template::snippets::Snippets;
+use serde::Deserialize;
+
+/// Configuration file such as `rstrial.toml`.
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    #[serde(default)]
+    pub convert: ConvertConfig,
+}
+
+/// `[convert]` table of the configuration file. Command line flags take precedence.
+#[derive(Debug, Default, Deserialize)]
+pub struct ConvertConfig {
+    pub scene_separator: Option<String>,
+    pub comments: Option<String>,
+    pub ruby: Option<String>,
+    pub sesame: Option<String>,
+    pub indent: Option<String>,
+    pub writing_mode: Option<String>,
+    pub page_grid: Option<String>,
+    pub dialogue_voice: Option<String>,
+}
+
+impl Config {
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(toml::from_str(&content)?)
+    }
+}
+
+/// Loads the template file given by `--template` for `--format template`.
+/// Snippets not given keep their defaults, and unknown keys are rejected to catch typos.
+pub fn load_template(path: &Path) -> Result<Snippets, Box<dyn std::error::Error>> {
+    let content = std::fs::read_to_string(path)?;
+    Ok(toml::from_str(&content)?)
+}
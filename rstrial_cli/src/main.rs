@@ -1,12 +1,13 @@
 pub mod commands;
+pub mod config;
 
 use log::{info, warn};
 
 use clap::Parser;
 
-use crate::commands::{check::CheckCommand, convert::ConvertCommand, Args, Command, Commands};
-
-type PathManuscriptTuple = (String, String);
+use crate::commands::{
+    check::CheckCommand, convert::ConvertCommand, import::ImportCommand, Args, Command, Commands,
+};
 
 fn main() {
     pretty_env_logger::init();
@@ -24,5 +25,10 @@ fn main() {
             CheckCommand.execute(&args).unwrap();
             warn!("finished checking!");
         }
+        Commands::Import(args) => {
+            warn!("start importing...");
+            ImportCommand.execute(&args).unwrap();
+            warn!("finished importing!");
+        }
     }
 }
@@ -1,92 +1,233 @@
+use std::io::{self, Write};
+
 use log::info;
-use rstrial_parser::tokens::{section::Section, Line, LineItem};
+use rstrial_parser::tokens::{line_item::Terminator, section::Section, Line, LineItem};
 
 pub mod aozora;
+pub mod asciidoc;
+pub mod daihon;
+pub mod docx;
+pub mod epub;
+pub mod fountain;
+pub mod html;
+pub mod indesign;
+pub mod kakuyomu;
+pub mod latex;
+pub mod narou;
+pub mod odt;
+pub mod options;
+pub mod pixiv;
+pub mod plain;
+pub mod review;
+pub mod rstrial;
+pub(crate) mod speaker;
+pub mod ssml;
+pub(crate) mod tatechuyoko;
+pub mod template;
+#[cfg(test)]
+mod test_archive;
+pub mod typst;
 pub mod vfm;
+pub(crate) mod xml;
+
+pub use options::{
+    CommentPolicy, ConverterOptions, PageGrid, RubyPolicy, SesamePolicy, WritingMode,
+};
+
+// Runs `write` against an in-memory buffer to implement the `convert` shorthands.
+fn write_to_string(write: impl FnOnce(&mut dyn Write) -> io::Result<()>) -> String {
+    let mut buffer = vec![];
+    write(&mut buffer).expect("writing into memory should not fail");
+    String::from_utf8(buffer).expect("converted text should be UTF-8")
+}
+
+// Draft and skipped scenes are left out of converted manuscripts.
+pub(crate) fn is_published(section: &Section) -> bool {
+    match section {
+        Section::Scene(document, _) => !document.is_draft() && !document.is_skipped(),
+        Section::Title(_) => true,
+    }
+}
 
 pub trait LineItemConverter {
-    fn convert(item: LineItem) -> String;
+    fn options(&self) -> &ConverterOptions;
+
+    fn convert(&self, item: LineItem) -> String;
+
+    fn write(&self, item: LineItem, writer: &mut dyn Write) -> io::Result<()> {
+        writer.write_all(self.convert(item).as_bytes())
+    }
 }
 
 pub trait LineConverter {
     type ItemConverter: LineItemConverter;
 
-    fn line_separator() -> String {
+    fn item_converter(&self) -> &Self::ItemConverter;
+
+    fn options(&self) -> &ConverterOptions {
+        self.item_converter().options()
+    }
+
+    fn line_separator(&self) -> String {
         "\n".to_string()
     }
 
-    fn convert(line: Line) -> String {
-        let breakline = "\n".to_string();
+    fn write_items(&self, items: Vec<LineItem>, writer: &mut dyn Write) -> io::Result<()> {
+        for item in items {
+            let item = self.options().apply_policies(item);
+            self.item_converter().write(item, writer)?;
+        }
+        Ok(())
+    }
+
+    /// Applies the policies to items, and turns an exclamation at the end of a line into
+    /// a normal terminator so that no space is left after it.
+    fn trim_items(&self, items: Vec<LineItem>) -> Vec<LineItem> {
+        let mut items: Vec<LineItem> = items
+            .into_iter()
+            .map(|item| self.options().apply_policies(item))
+            .collect();
+        if let Some(LineItem::EndOfSentence(Terminator::Exclamation(terminator))) = items.last() {
+            let terminator = Terminator::Normal(terminator.clone());
+            *items.last_mut().unwrap() = LineItem::EndOfSentence(terminator);
+        }
+        items
+    }
+
+    /// Writes items without the space after an exclamation at the end of a line,
+    /// for formats in which trailing spaces are visible or stripped.
+    fn write_items_trimmed(&self, items: Vec<LineItem>, writer: &mut dyn Write) -> io::Result<()> {
+        self.trim_items(items)
+            .into_iter()
+            .try_for_each(|item| self.item_converter().write(item, writer))
+    }
+
+    fn write_comment(&self, comment: String, writer: &mut dyn Write) -> io::Result<()> {
+        self.item_converter()
+            .write(LineItem::Comment(comment), writer)?;
+        write!(writer, "{}", self.line_separator())
+    }
+
+    fn write(&self, line: Line, writer: &mut dyn Write) -> io::Result<()> {
         match line {
-            Line::Paragraph(items) => format!(
-                "　{}{}",
-                items
-                    .into_iter()
-                    .map(Self::ItemConverter::convert)
-                    .collect::<Vec<String>>()
-                    .concat(),
-                Self::line_separator(),
-            ),
-            Line::Conversation(items) => format!(
-                " {}{}",
-                items
-                    .into_iter()
-                    .map(Self::ItemConverter::convert)
-                    .collect::<Vec<String>>()
-                    .concat(),
-                Self::line_separator(),
-            ),
-            Line::Quotation(items) => format!(
-                "> {}{}",
-                items
-                    .into_iter()
-                    .map(Self::ItemConverter::convert)
-                    .collect::<Vec<String>>()
-                    .concat(),
-                Self::line_separator(),
-            ),
-            Line::Comment(_) => breakline,
+            Line::Paragraph(items) => {
+                write!(writer, "{}", self.options().indent)?;
+                self.write_items(items, writer)?;
+            }
+            Line::Conversation(items) => {
+                write!(writer, " ")?;
+                self.write_items(items, writer)?;
+            }
+            Line::Quotation(items) => {
+                write!(writer, "> ")?;
+                self.write_items(items, writer)?;
+            }
+            Line::Comment(comment) => {
+                return match self.options().comments {
+                    CommentPolicy::Keep => self.write_comment(comment, writer),
+                    CommentPolicy::Drop => writeln!(writer),
+                }
+            }
+            // Dropped block comments leave no line behind, so that they never split paragraphs.
+            Line::BlockComment(comment) => {
+                return match self.options().comments {
+                    CommentPolicy::Keep => self.write_comment(comment, writer),
+                    CommentPolicy::Drop => Ok(()),
+                }
+            }
+            Line::AuthorOnly(_) => return Ok(()),
         }
+        write!(writer, "{}", self.line_separator())
+    }
+
+    fn convert(&self, line: Line) -> String {
+        write_to_string(|writer| self.write(line, writer))
     }
 }
 
 pub trait SectionConverter {
     type ItemConverter: LineConverter;
 
-    fn convert(section: Section) -> String {
+    fn item_converter(&self) -> &Self::ItemConverter;
+
+    fn options(&self) -> &ConverterOptions {
+        self.item_converter().options()
+    }
+
+    fn write(&self, section: Section, writer: &mut dyn Write) -> io::Result<()> {
         match section {
-            Section::Title(_) => "".to_string(),
+            Section::Title(_) => Ok(()),
             Section::Scene(_, body) => body
                 .into_iter()
-                .map(Self::ItemConverter::convert)
-                .collect::<Vec<String>>()
-                .concat(),
+                .try_for_each(|line| self.item_converter().write(line, writer)),
         }
     }
+
+    fn convert(&self, section: Section) -> String {
+        write_to_string(|writer| self.write(section, writer))
+    }
 }
 
 pub trait ManuscriptConverter {
     type ItemConverter: SectionConverter;
-    fn convert(sections: Vec<Section>) -> String {
-        let mut previous_section = None;
-        sections
-            .into_iter()
-            .map(|section| {
-                let section_string = match section {
-                    Section::Title(_) => {
-                        format!("{}\n", Self::ItemConverter::convert(section.clone()))
+
+    fn item_converter(&self) -> &Self::ItemConverter;
+
+    fn options(&self) -> &ConverterOptions {
+        self.item_converter().options()
+    }
+
+    /// Writes sections one by one so that a manuscript is never held in memory as a whole.
+    fn write(
+        &self,
+        sections: impl IntoIterator<Item = Section>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        let mut previous_was_title = false;
+        for section in sections.into_iter().filter(is_published) {
+            info!("convert: {:?}", section);
+            match section {
+                Section::Title(_) => {
+                    self.item_converter().write(section, writer)?;
+                    writeln!(writer)?;
+                    previous_was_title = true;
+                }
+                Section::Scene(_, _) => {
+                    if previous_was_title {
+                        writeln!(writer)?;
+                    } else {
+                        write!(writer, "\n{}\n\n", self.options().scene_separator)?;
                     }
-                    Section::Scene(_, _) => match previous_section {
-                        Some(Section::Title(_)) => {
-                            format!("\n{}", Self::ItemConverter::convert(section.clone()))
-                        }
-                        _ => format!("\n†\n\n{}", Self::ItemConverter::convert(section.clone())),
-                    },
-                };
-                info!("convert: {:?} -> `{}`", section, section_string);
-                previous_section = Some(section);
-                section_string
-            })
-            .collect()
+                    self.item_converter().write(section, writer)?;
+                    previous_was_title = false;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes only scenes separated by the scene separator, for web novel platforms
+    /// where titles are entered apart from the body.
+    fn write_scenes(
+        &self,
+        sections: impl IntoIterator<Item = Section>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        let scenes = sections
+            .into_iter()
+            .filter(is_published)
+            .filter(|section| matches!(section, Section::Scene(_, _)));
+        for (index, scene) in scenes.enumerate() {
+            info!("convert: {:?}", scene);
+            if index > 0 {
+                write!(writer, "\n{}\n\n", self.options().scene_separator)?;
+            }
+            self.item_converter().write(scene, writer)?;
+        }
+        Ok(())
+    }
+
+    fn convert(&self, sections: Vec<Section>) -> String {
+        write_to_string(|writer| self.write(sections, writer))
     }
 }
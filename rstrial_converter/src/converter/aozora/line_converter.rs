@@ -1,11 +1,26 @@
-use crate::converter::LineConverter;
+use crate::converter::{ConverterOptions, LineConverter};
 
 use super::line_item_converter::AozoraLineItemConverter;
 
-pub struct AozoraLineConverter;
+#[derive(Default)]
+pub struct AozoraLineConverter {
+    item_converter: AozoraLineItemConverter,
+}
+
+impl AozoraLineConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: AozoraLineItemConverter::new(options),
+        }
+    }
+}
 
 impl LineConverter for AozoraLineConverter {
     type ItemConverter = AozoraLineItemConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
 }
 
 #[cfg(test)]
@@ -27,7 +42,7 @@ mod tests {
             rstrial_parser::tokens::LineItem::Text("はまだ無い".to_string()),
             rstrial_parser::tokens::LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
         ]);
-        let result = AozoraLineConverter::convert(line);
+        let result = AozoraLineConverter::default().convert(line);
         assert_eq!(result, "　我が輩は、|名前《なまえ》はまだ無い。\n");
     }
 
@@ -44,7 +59,23 @@ mod tests {
             rstrial_parser::tokens::LineItem::Text("はまだ無い".to_string()),
             rstrial_parser::tokens::LineItem::EndOfSentence(Terminator::Normal("」".to_string())),
         ]);
-        let result = AozoraLineConverter::convert(line);
+        let result = AozoraLineConverter::default().convert(line);
         assert_eq!(result, " 「我が輩は、|名前《なまえ》はまだ無い」\n");
     }
+
+    #[test]
+    fn test_convert_author_only() {
+        let line = Line::AuthorOnly(vec![Line::Paragraph(vec![
+            rstrial_parser::tokens::LineItem::Text("名前はポチ".to_string()),
+        ])]);
+        assert_eq!(AozoraLineConverter::default().convert(line), "");
+        let line = Line::BlockComment("没案".to_string());
+        assert_eq!(AozoraLineConverter::default().convert(line), "");
+    }
+
+    #[test]
+    fn test_convert_comment() {
+        let line = Line::Comment("見当をつけるかどうか".to_string());
+        assert_eq!(AozoraLineConverter::default().convert(line), "\n");
+    }
 }
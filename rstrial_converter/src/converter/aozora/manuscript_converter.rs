@@ -1,9 +1,24 @@
-use crate::converter::ManuscriptConverter;
+use crate::converter::{ConverterOptions, ManuscriptConverter};
 
 use super::section_converter::AozoraSectionConverter;
 
-pub struct AozoraManuscriptConverter;
+#[derive(Default)]
+pub struct AozoraManuscriptConverter {
+    item_converter: AozoraSectionConverter,
+}
+
+impl AozoraManuscriptConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: AozoraSectionConverter::new(options),
+        }
+    }
+}
 
 impl ManuscriptConverter for AozoraManuscriptConverter {
     type ItemConverter = AozoraSectionConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
 }
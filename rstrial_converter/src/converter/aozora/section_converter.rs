@@ -1,9 +1,24 @@
-use crate::converter::SectionConverter;
+use crate::converter::{ConverterOptions, SectionConverter};
 
 use super::line_converter::AozoraLineConverter;
 
-pub struct AozoraSectionConverter;
+#[derive(Default)]
+pub struct AozoraSectionConverter {
+    item_converter: AozoraLineConverter,
+}
+
+impl AozoraSectionConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: AozoraLineConverter::new(options),
+        }
+    }
+}
 
 impl SectionConverter for AozoraSectionConverter {
     type ItemConverter = AozoraLineConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
 }
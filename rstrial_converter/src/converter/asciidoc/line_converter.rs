@@ -0,0 +1,93 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::Line;
+
+use crate::converter::{CommentPolicy, ConverterOptions, LineConverter};
+
+use super::line_item_converter::AsciidocLineItemConverter;
+
+#[derive(Default)]
+pub struct AsciidocLineConverter {
+    item_converter: AsciidocLineItemConverter,
+}
+
+impl AsciidocLineConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: AsciidocLineItemConverter::new(options),
+        }
+    }
+}
+
+impl LineConverter for AsciidocLineConverter {
+    type ItemConverter = AsciidocLineItemConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    // Consecutive lines are joined into one paragraph in AsciiDoc.
+    fn line_separator(&self) -> String {
+        "\n\n".to_string()
+    }
+
+    fn write(&self, line: Line, writer: &mut dyn Write) -> io::Result<()> {
+        match line {
+            Line::Paragraph(items) if items.is_empty() => write!(writer, "{{nbsp}}")?,
+            Line::Paragraph(items) => {
+                write!(writer, "{}", self.options().indent)?;
+                self.write_items(items, writer)?;
+            }
+            Line::Conversation(items) => self.write_items(items, writer)?,
+            Line::Quotation(items) => {
+                write!(writer, "> ")?;
+                self.write_items(items, writer)?;
+            }
+            Line::Comment(comment) => {
+                return match self.options().comments {
+                    CommentPolicy::Keep => write!(writer, "//{}{}", comment, self.line_separator()),
+                    CommentPolicy::Drop => Ok(()),
+                }
+            }
+            Line::BlockComment(comment) => {
+                return match self.options().comments {
+                    CommentPolicy::Keep => {
+                        write!(writer, "////\n{}\n////{}", comment, self.line_separator())
+                    }
+                    CommentPolicy::Drop => Ok(()),
+                }
+            }
+            Line::AuthorOnly(_) => return Ok(()),
+        }
+        write!(writer, "{}", self.line_separator())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::tokens::{line_item::Terminator, LineItem};
+
+    use super::*;
+
+    #[test]
+    fn test_convert_quotation() {
+        let line = Line::Quotation(vec![
+            LineItem::Text("「どうも".to_string()),
+            LineItem::EndOfSentence(Terminator::Normal("」".to_string())),
+        ]);
+        let result = AsciidocLineConverter::default().convert(line);
+        assert_eq!(result, "> 「どうも」\n\n");
+    }
+
+    #[test]
+    fn test_convert_comment() {
+        let converter = AsciidocLineConverter::new(ConverterOptions {
+            comments: CommentPolicy::Keep,
+            ..Default::default()
+        });
+        let result = converter.convert(Line::BlockComment("没\n案".to_string()));
+        assert_eq!(result, "////\n没\n案\n////\n\n");
+        let result = AsciidocLineConverter::default().convert(Line::Comment(" メモ".to_string()));
+        assert_eq!(result, "");
+    }
+}
@@ -0,0 +1,62 @@
+use rstrial_parser::tokens::{line_item::Terminator, LineItem};
+
+use crate::converter::{xml, CommentPolicy, ConverterOptions, LineItemConverter};
+
+/// Converts items into AsciiDoc. AsciiDoc has no ruby, so ruby is passed through as HTML.
+#[derive(Default)]
+pub struct AsciidocLineItemConverter {
+    options: ConverterOptions,
+}
+
+impl AsciidocLineItemConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl LineItemConverter for AsciidocLineItemConverter {
+    fn options(&self) -> &ConverterOptions {
+        &self.options
+    }
+
+    fn convert(&self, item: LineItem) -> String {
+        match item {
+            LineItem::Text(text) => text,
+            LineItem::Comma(comma) => comma,
+            LineItem::Comment(comment) => match self.options.comments {
+                CommentPolicy::Keep => format!("+++{}+++", xml::comment(&comment)),
+                CommentPolicy::Drop => "".to_string(),
+            },
+            LineItem::TextWithRuby((text, ruby)) => format!(
+                "+++<ruby>{}<rt>{}</rt></ruby>+++",
+                xml::escape(&text),
+                xml::escape(&ruby)
+            ),
+            LineItem::EndOfSentence(Terminator::Normal(terminator)) => terminator,
+            LineItem::EndOfSentence(Terminator::Exclamation(terminator)) => {
+                format!("{}　", terminator)
+            }
+            LineItem::EndOfSection(_) => "".to_string(),
+            LineItem::TextWithSesame((text, _)) => format!("[.sesame]#{}#", text),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_rich_text() {
+        let item = LineItem::TextWithRuby(("吾輩".to_string(), "わがはい".to_string()));
+        let result = AsciidocLineItemConverter::default().convert(item);
+        assert_eq!(result, "+++<ruby>吾輩<rt>わがはい</rt></ruby>+++");
+    }
+
+    #[test]
+    fn test_convert_text_with_sesame() {
+        let item = LineItem::TextWithSesame(("まだ".to_string(), '・'));
+        let result = AsciidocLineItemConverter::default().convert(item);
+        assert_eq!(result, "[.sesame]#まだ#");
+    }
+}
@@ -0,0 +1,92 @@
+use std::io::{self, Write};
+
+use log::info;
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{is_published, ConverterOptions, ManuscriptConverter, SectionConverter};
+
+use super::section_converter::AsciidocSectionConverter;
+
+/// Converts a manuscript into an AsciiDoc book for Asciidoctor.
+///
+/// Titles become level 0 sections and scenes are separated by centered scene separators.
+/// Sesame is marked with the `sesame` role, which can be styled by `text-emphasis`.
+#[derive(Default)]
+pub struct AsciidocManuscriptConverter {
+    item_converter: AsciidocSectionConverter,
+}
+
+impl AsciidocManuscriptConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: AsciidocSectionConverter::new(options),
+        }
+    }
+}
+
+impl ManuscriptConverter for AsciidocManuscriptConverter {
+    type ItemConverter = AsciidocSectionConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(
+        &self,
+        sections: impl IntoIterator<Item = Section>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        let mut previous_was_scene = false;
+        for (index, section) in sections.into_iter().filter(is_published).enumerate() {
+            info!("convert: {:?}", section);
+            let is_scene = matches!(section, Section::Scene(_, _));
+            match (&section, previous_was_scene) {
+                // Header attributes have to follow the document title.
+                (Section::Title(title), _) if index == 0 => {
+                    writeln!(writer, "= {}\n:lang: ja\n:doctype: book\n", title)?
+                }
+                (Section::Scene(_, _), true) => {
+                    writeln!(
+                        writer,
+                        "[.text-center]\n{}\n",
+                        self.options().scene_separator
+                    )?;
+                    self.item_converter().write(section, writer)?;
+                }
+                _ => self.item_converter().write(section, writer)?,
+            }
+            previous_was_scene = is_scene;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::{AsciidocParser, ManuscriptParser};
+
+    use crate::converter::rstrial::manuscript_converter::RstrialManuscriptConverter;
+
+    use super::*;
+
+    const INPUT: &str = "# 表題\n\n```\n{吾輩|わがはい}は猫である。名前は{まだ|.}無い！\n\nにゃあ。\n```\n\n```\n「どうも{甘|うま}くかけないものだね」\n```\n";
+
+    #[test]
+    fn test_convert() {
+        let result =
+            AsciidocManuscriptConverter::default().convert(ManuscriptParser::new(INPUT).collect());
+        assert_eq!(
+            result,
+            "= 表題\n:lang: ja\n:doctype: book\n\n　+++<ruby>吾輩<rt>わがはい</rt></ruby>+++は猫である。名前は[.sesame]#まだ#無い！　\n\n{nbsp}\n\n　にゃあ。\n\n[.text-center]\n†\n\n「どうも+++<ruby>甘<rt>うま</rt></ruby>+++くかけないものだね」\n\n"
+        );
+    }
+
+    #[test]
+    fn test_convert_and_import() {
+        let asciidoc =
+            AsciidocManuscriptConverter::default().convert(ManuscriptParser::new(INPUT).collect());
+        let result =
+            RstrialManuscriptConverter::default().convert(AsciidocParser::new(&asciidoc).collect());
+        assert_eq!(result, INPUT);
+    }
+}
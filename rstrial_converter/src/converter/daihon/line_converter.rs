@@ -0,0 +1,90 @@
+use std::io::{self, Write};
+
+use log::warn;
+use rstrial_parser::tokens::{Line, LineItem};
+
+use crate::converter::{speaker, CommentPolicy, ConverterOptions, LineConverter};
+
+use super::line_item_converter::DaihonLineItemConverter;
+
+/// Indent of ト書き, which sits lower than dialogue.
+pub const TOGAKI_INDENT: &str = "　　　";
+
+#[derive(Default)]
+pub struct DaihonLineConverter {
+    item_converter: DaihonLineItemConverter,
+}
+
+impl DaihonLineConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: DaihonLineItemConverter::new(options),
+        }
+    }
+
+    /// Writes dialogue as `名前「……」`.
+    pub fn write_dialogue(
+        &self,
+        speaker: &str,
+        items: Vec<LineItem>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        write!(writer, "{}「", speaker)?;
+        self.write_items(speaker::unquote(items), writer)?;
+        write!(writer, "」{}", self.line_separator())
+    }
+}
+
+impl LineConverter for DaihonLineConverter {
+    type ItemConverter = DaihonLineItemConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write_items(&self, items: Vec<LineItem>, writer: &mut dyn Write) -> io::Result<()> {
+        self.write_items_trimmed(items, writer)
+    }
+
+    fn write(&self, line: Line, writer: &mut dyn Write) -> io::Result<()> {
+        match line {
+            Line::Paragraph(items) if items.is_empty() => {}
+            Line::Paragraph(items) | Line::Quotation(items) => {
+                write!(writer, "{}", TOGAKI_INDENT)?;
+                self.write_items(items, writer)?;
+            }
+            Line::Conversation(items) => {
+                warn!("dialogue without a speaker: {:?}", items);
+                self.write_items(items, writer)?;
+            }
+            Line::Comment(comment) | Line::BlockComment(comment) => {
+                if self.options().comments == CommentPolicy::Keep {
+                    for line in comment.lines() {
+                        writeln!(writer, "※{}", line)?;
+                    }
+                }
+                return Ok(());
+            }
+            Line::AuthorOnly(_) => return Ok(()),
+        }
+        write!(writer, "{}", self.line_separator())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::tokens::line_item::Terminator;
+
+    use super::*;
+
+    #[test]
+    fn test_convert_togaki() {
+        let line = Line::Paragraph(vec![
+            LineItem::TextWithRuby(("吾輩".to_string(), "わがはい".to_string())),
+            LineItem::Text("が鳴く".to_string()),
+            LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+        ]);
+        let result = DaihonLineConverter::default().convert(line);
+        assert_eq!(result, "　　　吾輩（わがはい）が鳴く。\n");
+    }
+}
@@ -0,0 +1,42 @@
+use rstrial_parser::tokens::{line_item::Terminator, LineItem};
+
+use crate::converter::{CommentPolicy, ConverterOptions, LineItemConverter};
+
+/// Converts items into script text without markup.
+///
+/// Ruby kept by the ruby policy is parenthesized for the cast to read,
+/// and sesame kept by the sesame policy is dropped.
+#[derive(Default)]
+pub struct DaihonLineItemConverter {
+    options: ConverterOptions,
+}
+
+impl DaihonLineItemConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl LineItemConverter for DaihonLineItemConverter {
+    fn options(&self) -> &ConverterOptions {
+        &self.options
+    }
+
+    fn convert(&self, item: LineItem) -> String {
+        match item {
+            LineItem::Text(text) => text,
+            LineItem::Comma(comma) => comma,
+            LineItem::Comment(comment) => match self.options.comments {
+                CommentPolicy::Keep => format!("（※{comment}）"),
+                CommentPolicy::Drop => "".to_string(),
+            },
+            LineItem::TextWithRuby((text, ruby)) => format!("{text}（{ruby}）"),
+            LineItem::TextWithSesame((text, _)) => text,
+            LineItem::EndOfSentence(Terminator::Normal(terminator)) => terminator,
+            LineItem::EndOfSentence(Terminator::Exclamation(terminator)) => {
+                format!("{}　", terminator)
+            }
+            LineItem::EndOfSection(_) => "".to_string(),
+        }
+    }
+}
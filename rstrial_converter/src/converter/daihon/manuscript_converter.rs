@@ -0,0 +1,78 @@
+use std::io::{self, Write};
+
+use log::info;
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{is_published, ConverterOptions, ManuscriptConverter, SectionConverter};
+
+use super::section_converter::DaihonSectionConverter;
+
+/// Converts a manuscript into a Japanese script (台本形式) for audio dramas.
+///
+/// Conversations become `名前「……」` of the speakers given by `@characters` or `{#@name}`,
+/// other lines become indented ト書き, and scene titles become 柱.
+#[derive(Default)]
+pub struct DaihonManuscriptConverter {
+    item_converter: DaihonSectionConverter,
+}
+
+impl DaihonManuscriptConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: DaihonSectionConverter::new(options),
+        }
+    }
+}
+
+impl ManuscriptConverter for DaihonManuscriptConverter {
+    type ItemConverter = DaihonSectionConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(
+        &self,
+        sections: impl IntoIterator<Item = Section>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        let mut previous_was_scene = false;
+        for (index, section) in sections.into_iter().filter(is_published).enumerate() {
+            info!("convert: {:?}", section);
+            let is_scene = matches!(section, Section::Scene(_, _));
+            match (&section, previous_was_scene) {
+                _ if index == 0 => {}
+                // 柱 divide titled scenes, so only untitled ones need the separator.
+                (Section::Scene(document, _), true)
+                    if document.title.trim().is_empty()
+                        && !self.options().scene_separator.is_empty() =>
+                {
+                    write!(writer, "\n{}\n\n", self.options().scene_separator)?
+                }
+                _ => writeln!(writer)?,
+            }
+            self.item_converter().write(section, writer)?;
+            previous_was_scene = is_scene;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::ManuscriptParser;
+
+    use super::*;
+
+    const INPUT: &str = "# 吾輩は猫である\n\n@characters 猫 主人\n```書斎\n{吾輩|わがはい}は猫である。\n「にゃあ」\n主人「うるさい！」\n```\n```\n{#@猫}「名前は{まだ|.}無い」\n「どこ？」\n```\n";
+
+    #[test]
+    fn test_convert() {
+        let result =
+            DaihonManuscriptConverter::default().convert(ManuscriptParser::new(INPUT).collect());
+        assert_eq!(
+            result,
+            "吾輩は猫である\n\n○書斎\n　　　吾輩（わがはい）は猫である。\n「にゃあ」\n主人「うるさい！」\n\n†\n\n猫「名前はまだ無い」\n「どこ？」\n"
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{
+    speaker::{self, Attributed},
+    ConverterOptions, LineConverter, SectionConverter,
+};
+
+use super::line_converter::DaihonLineConverter;
+
+#[derive(Default)]
+pub struct DaihonSectionConverter {
+    item_converter: DaihonLineConverter,
+}
+
+impl DaihonSectionConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: DaihonLineConverter::new(options),
+        }
+    }
+}
+
+impl SectionConverter for DaihonSectionConverter {
+    type ItemConverter = DaihonLineConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    // Scene titles become 柱 such as `○書斎`.
+    fn write(&self, section: Section, writer: &mut dyn Write) -> io::Result<()> {
+        match section {
+            Section::Title(title) => writeln!(writer, "{}", title),
+            Section::Scene(document, body) => {
+                if !document.title.trim().is_empty() {
+                    writeln!(writer, "○{}", document.title.trim())?;
+                }
+                for line in body {
+                    match speaker::attribute(line, &document.characters) {
+                        Attributed::Dialogue(speaker, items) => self
+                            .item_converter()
+                            .write_dialogue(&speaker, items, writer)?,
+                        Attributed::Other(line) => self.item_converter().write(line, writer)?,
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
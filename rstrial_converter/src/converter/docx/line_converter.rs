@@ -0,0 +1,107 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::Line;
+
+use crate::converter::{CommentPolicy, ConverterOptions, LineConverter};
+
+use super::line_item_converter::DocxLineItemConverter;
+
+#[derive(Default)]
+pub struct DocxLineConverter {
+    item_converter: DocxLineItemConverter,
+}
+
+impl DocxLineConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: DocxLineItemConverter::new(options),
+        }
+    }
+}
+
+impl LineConverter for DocxLineConverter {
+    type ItemConverter = DocxLineItemConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(&self, line: Line, writer: &mut dyn Write) -> io::Result<()> {
+        match line {
+            Line::Paragraph(items) if items.is_empty() => write!(writer, "<w:p/>")?,
+            Line::Paragraph(items) => {
+                write!(
+                    writer,
+                    "<w:p>{}",
+                    DocxLineItemConverter::run(&self.options().indent)
+                )?;
+                self.write_items(items, writer)?;
+                write!(writer, "</w:p>")?;
+            }
+            Line::Conversation(items) => {
+                write!(writer, "<w:p>")?;
+                self.write_items(items, writer)?;
+                write!(writer, "</w:p>")?;
+            }
+            Line::Quotation(items) => {
+                write!(
+                    writer,
+                    "<w:p><w:pPr><w:ind w:leftChars=\"200\" w:left=\"420\"/></w:pPr>"
+                )?;
+                self.write_items(items, writer)?;
+                write!(writer, "</w:p>")?;
+            }
+            // Comments are kept as hidden paragraphs, including their paragraph marks.
+            Line::Comment(comment) | Line::BlockComment(comment) => match self.options().comments {
+                CommentPolicy::Keep => {
+                    for line in comment.lines() {
+                        write!(
+                            writer,
+                            "<w:p><w:pPr><w:rPr><w:vanish/></w:rPr></w:pPr>{}</w:p>{}",
+                            DocxLineItemConverter::hidden_run(line),
+                            self.line_separator()
+                        )?;
+                    }
+                    return Ok(());
+                }
+                CommentPolicy::Drop => return Ok(()),
+            },
+            Line::AuthorOnly(_) => return Ok(()),
+        }
+        write!(writer, "{}", self.line_separator())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::tokens::{line_item::Terminator, LineItem};
+
+    use super::*;
+
+    #[test]
+    fn test_convert_paragraph() {
+        let line = Line::Paragraph(vec![
+            LineItem::Text("吾輩は猫である".to_string()),
+            LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+        ]);
+        let result = DocxLineConverter::default().convert(line);
+        assert_eq!(
+            result,
+            "<w:p><w:r><w:t xml:space=\"preserve\">　</w:t></w:r><w:r><w:t xml:space=\"preserve\">吾輩は猫である</w:t></w:r><w:r><w:t xml:space=\"preserve\">。</w:t></w:r></w:p>\n"
+        );
+    }
+
+    #[test]
+    fn test_convert_comment() {
+        let line = Line::Comment("メモ".to_string());
+        let converter = DocxLineConverter::new(ConverterOptions {
+            comments: CommentPolicy::Keep,
+            ..Default::default()
+        });
+        assert_eq!(
+            converter.convert(line.clone()),
+            "<w:p><w:pPr><w:rPr><w:vanish/></w:rPr></w:pPr><w:r><w:rPr><w:vanish/></w:rPr><w:t xml:space=\"preserve\">メモ</w:t></w:r></w:p>\n"
+        );
+        assert_eq!(DocxLineConverter::default().convert(line), "");
+    }
+}
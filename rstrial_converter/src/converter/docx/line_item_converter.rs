@@ -0,0 +1,121 @@
+use rstrial_parser::tokens::{line_item::Terminator, LineItem};
+
+use crate::converter::{xml, CommentPolicy, ConverterOptions, LineItemConverter};
+
+use super::package::FONT_SIZE;
+
+/// Font size of ruby in half-points.
+const RUBY_FONT_SIZE: u32 = 10;
+
+/// Converts items into WordprocessingML runs.
+#[derive(Default)]
+pub struct DocxLineItemConverter {
+    options: ConverterOptions,
+}
+
+impl DocxLineItemConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self { options }
+    }
+
+    /// Formats a run of plain text.
+    pub fn run(text: &str) -> String {
+        Self::run_with_properties(text, "")
+    }
+
+    /// Formats a run of hidden text, which Word shows only when formatting marks are visible.
+    pub fn hidden_run(text: &str) -> String {
+        Self::run_with_properties(text, "<w:vanish/>")
+    }
+
+    fn run_with_properties(text: &str, properties: &str) -> String {
+        if text.is_empty() {
+            return "".to_string();
+        }
+        let properties = match properties {
+            "" => "".to_string(),
+            properties => format!("<w:rPr>{}</w:rPr>", properties),
+        };
+        format!(
+            "<w:r>{}<w:t xml:space=\"preserve\">{}</w:t></w:r>",
+            properties,
+            xml::escape(text)
+        )
+    }
+
+    fn ruby(text: &str, ruby: &str) -> String {
+        format!(
+            "<w:r><w:ruby><w:rubyPr><w:rubyAlign w:val=\"distributeSpace\"/><w:hps w:val=\"{}\"/><w:hpsRaise w:val=\"{}\"/><w:hpsBaseText w:val=\"{}\"/><w:lid w:val=\"ja-JP\"/></w:rubyPr><w:rt>{}</w:rt><w:rubyBase>{}</w:rubyBase></w:ruby></w:r>",
+            RUBY_FONT_SIZE,
+            FONT_SIZE - 3,
+            FONT_SIZE,
+            Self::run_with_properties(ruby, &format!("<w:sz w:val=\"{}\"/>", RUBY_FONT_SIZE)),
+            Self::run(text)
+        )
+    }
+}
+
+impl LineItemConverter for DocxLineItemConverter {
+    fn options(&self) -> &ConverterOptions {
+        &self.options
+    }
+
+    fn convert(&self, item: LineItem) -> String {
+        match item {
+            LineItem::Text(text) => Self::run(&text),
+            LineItem::Comma(comma) => Self::run(&comma),
+            LineItem::Comment(comment) => match self.options.comments {
+                CommentPolicy::Keep => Self::hidden_run(&comment),
+                CommentPolicy::Drop => "".to_string(),
+            },
+            LineItem::TextWithRuby((text, ruby)) => Self::ruby(&text, &ruby),
+            LineItem::EndOfSentence(Terminator::Normal(terminator)) => Self::run(&terminator),
+            LineItem::EndOfSentence(Terminator::Exclamation(terminator)) => {
+                Self::run(&format!("{}　", terminator))
+            }
+            LineItem::EndOfSection(_) => "".to_string(),
+            LineItem::TextWithSesame((text, mark)) => {
+                // Word has no sesame mark; `comma` is the closest to `﹅`.
+                let em = match mark {
+                    '﹅' | '﹆' => "comma",
+                    _ => "dot",
+                };
+                Self::run_with_properties(&text, &format!("<w:em w:val=\"{}\"/>", em))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_text() {
+        let item = LineItem::Text("猫 & 犬".to_string());
+        let result = DocxLineItemConverter::default().convert(item);
+        assert_eq!(
+            result,
+            "<w:r><w:t xml:space=\"preserve\">猫 &amp; 犬</w:t></w:r>"
+        );
+    }
+
+    #[test]
+    fn test_convert_rich_text() {
+        let item = LineItem::TextWithRuby(("吾輩".to_string(), "わがはい".to_string()));
+        let result = DocxLineItemConverter::default().convert(item);
+        assert!(result.starts_with("<w:r><w:ruby><w:rubyPr>"));
+        assert!(result.contains("<w:rt><w:r><w:rPr><w:sz w:val=\"10\"/></w:rPr><w:t xml:space=\"preserve\">わがはい</w:t></w:r></w:rt>"));
+        assert!(result.ends_with("<w:rubyBase><w:r><w:t xml:space=\"preserve\">吾輩</w:t></w:r></w:rubyBase></w:ruby></w:r>"));
+    }
+
+    #[test]
+    fn test_convert_text_with_sesame() {
+        let item = LineItem::TextWithSesame(("まだ".to_string(), '・'));
+        let result = DocxLineItemConverter::default().convert(item);
+        assert_eq!(
+            result,
+            "<w:r><w:rPr><w:em w:val=\"dot\"/></w:rPr><w:t xml:space=\"preserve\">まだ</w:t></w:r>"
+        );
+    }
+}
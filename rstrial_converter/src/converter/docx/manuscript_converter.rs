@@ -0,0 +1,148 @@
+use std::io::{self, Write};
+
+use log::info;
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{is_published, ConverterOptions, ManuscriptConverter, SectionConverter};
+
+use super::{
+    line_item_converter::DocxLineItemConverter, package::Package,
+    section_converter::DocxSectionConverter,
+};
+
+/// Converts a manuscript into a Word document.
+///
+/// Vertical documents use landscape A4 pages with the page grid of the options.
+/// Each `# ` title starts a new page, and the first one becomes the title of the document.
+/// The output is a zip archive, so use `write` instead of `convert`.
+#[derive(Default)]
+pub struct DocxManuscriptConverter {
+    item_converter: DocxSectionConverter,
+}
+
+impl DocxManuscriptConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: DocxSectionConverter::new(options),
+        }
+    }
+}
+
+impl ManuscriptConverter for DocxManuscriptConverter {
+    type ItemConverter = DocxSectionConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(
+        &self,
+        sections: impl IntoIterator<Item = Section>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        let mut package = Package {
+            writing_mode: self.options().writing_mode,
+            page_grid: self.options().page_grid,
+            ..Default::default()
+        };
+        let mut previous_was_scene = false;
+        for section in sections.into_iter().filter(is_published) {
+            info!("convert: {:?}", section);
+            let is_scene = matches!(section, Section::Scene(_, _));
+            match (&section, previous_was_scene) {
+                (Section::Title(title), _) => {
+                    package.title.get_or_insert_with(|| title.clone());
+                }
+                (Section::Scene(_, _), true) => writeln!(
+                    package.body,
+                    "<w:p><w:pPr><w:pStyle w:val=\"SceneSeparator\"/></w:pPr>{}</w:p>",
+                    DocxLineItemConverter::run(&self.options().scene_separator)
+                )?,
+                _ => {}
+            }
+            self.item_converter().write(section, &mut package.body)?;
+            previous_was_scene = is_scene;
+        }
+        package.write(writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quick_xml::{events::Event, Reader};
+
+    use crate::converter::{
+        test_archive::{convert, read, INPUT},
+        PageGrid, WritingMode,
+    };
+
+    use super::*;
+
+    // Counts start and empty tags named `element` and fails if the XML is not well-formed.
+    fn count(xml: &str, element: &str) -> usize {
+        let mut reader = Reader::from_str(xml);
+        let mut count = 0;
+        loop {
+            match reader.read_event() {
+                Ok(Event::Start(tag)) | Ok(Event::Empty(tag)) => {
+                    if tag.name().as_ref() == element.as_bytes() {
+                        count += 1;
+                    }
+                }
+                Ok(Event::Eof) => break,
+                Ok(_) => {}
+                Err(error) => panic!("malformed XML: {:?}\n{}", error, xml),
+            }
+        }
+        count
+    }
+
+    #[test]
+    fn test_package_structure() {
+        let mut archive = convert(DocxManuscriptConverter::default(), INPUT);
+        for name in [
+            "[Content_Types].xml",
+            "_rels/.rels",
+            "docProps/core.xml",
+            "word/_rels/document.xml.rels",
+            "word/styles.xml",
+        ] {
+            count(&read(&mut archive, name), "");
+        }
+        assert!(
+            read(&mut archive, "docProps/core.xml").contains("<dc:title>吾輩は猫である</dc:title>")
+        );
+        let styles = read(&mut archive, "word/styles.xml");
+        assert_eq!(count(&styles, "w:pageBreakBefore"), 1);
+    }
+
+    #[test]
+    fn test_document() {
+        let mut archive = convert(DocxManuscriptConverter::default(), INPUT);
+        let document = read(&mut archive, "word/document.xml");
+        assert_eq!(count(&document, "w:ruby"), 1);
+        assert_eq!(count(&document, "w:em"), 1);
+        assert_eq!(count(&document, "w:pStyle"), 3);
+        assert!(document.contains("<w:pStyle w:val=\"Heading1\"/></w:pPr><w:r><w:t xml:space=\"preserve\">二章</w:t></w:r>"));
+        assert!(document.contains("<w:pStyle w:val=\"SceneSeparator\"/></w:pPr><w:r><w:t xml:space=\"preserve\">†</w:t></w:r>"));
+        assert!(document.contains("<w:textDirection w:val=\"tbRl\"/>"));
+        assert!(document.contains("w:linePitch=\"465\""));
+    }
+
+    #[test]
+    fn test_horizontal_document() {
+        let converter = DocxManuscriptConverter::new(ConverterOptions {
+            writing_mode: WritingMode::Horizontal,
+            page_grid: PageGrid {
+                chars: 40,
+                lines: 36,
+            },
+            ..Default::default()
+        });
+        let mut archive = convert(converter, INPUT);
+        let document = read(&mut archive, "word/document.xml");
+        assert!(!document.contains("w:textDirection"));
+        // (16838 - 2880) / 36 = 387 twips per line.
+        assert!(document.contains("w:linePitch=\"387\""));
+    }
+}
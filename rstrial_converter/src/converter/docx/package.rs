@@ -0,0 +1,163 @@
+use std::io::{self, Cursor, Write};
+
+use zip::{write::FileOptions, CompressionMethod, ZipWriter};
+
+use crate::converter::{xml, PageGrid, WritingMode};
+
+/// Font size of the body text in half-points.
+pub(crate) const FONT_SIZE: u32 = 21;
+
+/// Page margin in twentieths of a point.
+const MARGIN: u32 = 1440;
+
+/// Width and height of A4 paper in twentieths of a point.
+const A4: (u32, u32) = (11906, 16838);
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+<Default Extension="xml" ContentType="application/xml"/>
+<Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/>
+<Override PartName="/word/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml"/>
+<Override PartName="/docProps/core.xml" ContentType="application/vnd.openxmlformats-package.core-properties+xml"/>
+</Types>
+"#;
+
+const RELATIONSHIPS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/>
+<Relationship Id="rId2" Type="http://schemas.openxmlformats.org/package/2006/relationships/metadata/core-properties" Target="docProps/core.xml"/>
+</Relationships>
+"#;
+
+const DOCUMENT_RELATIONSHIPS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/>
+</Relationships>
+"#;
+
+/// Contents of a WordprocessingML package. `body` holds the paragraphs of `<w:body>`.
+#[derive(Default)]
+pub(crate) struct Package {
+    pub title: Option<String>,
+    pub body: Vec<u8>,
+    pub writing_mode: WritingMode,
+    pub page_grid: PageGrid,
+}
+
+impl Package {
+    /// Writes the package as a zip archive.
+    pub fn write(&self, writer: &mut dyn Write) -> io::Result<()> {
+        let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+        let mut zip = ZipWriter::new(Cursor::new(vec![]));
+        zip.start_file("[Content_Types].xml", options)?;
+        zip.write_all(CONTENT_TYPES.as_bytes())?;
+        zip.start_file("_rels/.rels", options)?;
+        zip.write_all(RELATIONSHIPS.as_bytes())?;
+        zip.start_file("docProps/core.xml", options)?;
+        zip.write_all(self.core_properties().as_bytes())?;
+        zip.start_file("word/_rels/document.xml.rels", options)?;
+        zip.write_all(DOCUMENT_RELATIONSHIPS.as_bytes())?;
+        zip.start_file("word/styles.xml", options)?;
+        zip.write_all(Self::styles().as_bytes())?;
+        zip.start_file("word/document.xml", options)?;
+        zip.write_all(
+            br#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:body>
+"#,
+        )?;
+        zip.write_all(&self.body)?;
+        zip.write_all(self.section_properties().as_bytes())?;
+        zip.write_all(b"</w:body>\n</w:document>\n")?;
+        writer.write_all(&zip.finish()?.into_inner())
+    }
+
+    fn core_properties(&self) -> String {
+        let title = match &self.title {
+            Some(title) => format!("<dc:title>{}</dc:title>\n", xml::escape(title)),
+            None => "".to_string(),
+        };
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<cp:coreProperties xmlns:cp="http://schemas.openxmlformats.org/package/2006/metadata/core-properties" xmlns:dc="http://purl.org/dc/elements/1.1/">
+{}<dc:language>ja-JP</dc:language>
+</cp:coreProperties>
+"#,
+            title
+        )
+    }
+
+    fn styles() -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:docDefaults>
+<w:rPrDefault><w:rPr><w:rFonts w:eastAsia="游明朝"/><w:kern w:val="2"/><w:sz w:val="{0}"/><w:szCs w:val="{0}"/><w:lang w:val="en-US" w:eastAsia="ja-JP"/></w:rPr></w:rPrDefault>
+<w:pPrDefault><w:pPr><w:jc w:val="both"/></w:pPr></w:pPrDefault>
+</w:docDefaults>
+<w:style w:type="paragraph" w:default="1" w:styleId="Normal"><w:name w:val="Normal"/><w:qFormat/></w:style>
+<w:style w:type="paragraph" w:styleId="Heading1"><w:name w:val="heading 1"/><w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:qFormat/><w:pPr><w:keepNext/><w:pageBreakBefore/><w:spacing w:after="240"/><w:outlineLvl w:val="0"/></w:pPr><w:rPr><w:rFonts w:eastAsia="游ゴシック"/><w:b/><w:sz w:val="32"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="SceneSeparator"><w:name w:val="Scene Separator"/><w:basedOn w:val="Normal"/><w:pPr><w:jc w:val="center"/></w:pPr></w:style>
+</w:styles>
+"#,
+            FONT_SIZE
+        )
+    }
+
+    // Vertical documents are laid out on landscape pages like Japanese manuscript paper.
+    fn section_properties(&self) -> String {
+        let (width, height) = match self.writing_mode {
+            WritingMode::Vertical => (A4.1, A4.0),
+            WritingMode::Horizontal => A4,
+        };
+        let (line_length, page_depth) = match self.writing_mode {
+            WritingMode::Vertical => (height - MARGIN * 2, width - MARGIN * 2),
+            WritingMode::Horizontal => (width - MARGIN * 2, height - MARGIN * 2),
+        };
+        let line_pitch = page_depth / self.page_grid.lines;
+        // `w:charSpace` is the pitch added to the font size, in 4096ths of a point.
+        let char_pitch = f64::from(line_length) / f64::from(self.page_grid.chars) / 20.0;
+        let char_space = ((char_pitch - f64::from(FONT_SIZE) / 2.0) * 4096.0).round() as i64;
+        let (orientation, text_direction) = match self.writing_mode {
+            WritingMode::Vertical => (
+                " w:orient=\"landscape\"",
+                "<w:textDirection w:val=\"tbRl\"/>",
+            ),
+            WritingMode::Horizontal => ("", ""),
+        };
+        let margin = MARGIN;
+        format!(
+            "<w:sectPr><w:pgSz w:w=\"{width}\" w:h=\"{height}\"{orientation}/><w:pgMar w:top=\"{margin}\" w:right=\"{margin}\" w:bottom=\"{margin}\" w:left=\"{margin}\" w:header=\"720\" w:footer=\"720\" w:gutter=\"0\"/>{text_direction}<w:docGrid w:type=\"linesAndChars\" w:linePitch=\"{line_pitch}\" w:charSpace=\"{char_space}\"/></w:sectPr>\n"
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_section_properties() {
+        let package = Package {
+            page_grid: PageGrid {
+                chars: 20,
+                lines: 20,
+            },
+            ..Default::default()
+        };
+        let properties = package.section_properties();
+        assert!(properties.contains("<w:pgSz w:w=\"16838\" w:h=\"11906\" w:orient=\"landscape\"/>"));
+        assert!(properties.contains("<w:textDirection w:val=\"tbRl\"/>"));
+        // (11906 - 2880) / 20 = 451.3 twips = 22.565 points per character.
+        assert!(properties.contains("w:linePitch=\"697\" w:charSpace=\"49418\""));
+
+        let package = Package {
+            writing_mode: WritingMode::Horizontal,
+            ..Default::default()
+        };
+        let properties = package.section_properties();
+        assert!(properties.contains("<w:pgSz w:w=\"11906\" w:h=\"16838\"/>"));
+        assert!(!properties.contains("textDirection"));
+    }
+}
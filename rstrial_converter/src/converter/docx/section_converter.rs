@@ -0,0 +1,42 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{ConverterOptions, LineConverter, SectionConverter};
+
+use super::{line_converter::DocxLineConverter, line_item_converter::DocxLineItemConverter};
+
+#[derive(Default)]
+pub struct DocxSectionConverter {
+    item_converter: DocxLineConverter,
+}
+
+impl DocxSectionConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: DocxLineConverter::new(options),
+        }
+    }
+}
+
+impl SectionConverter for DocxSectionConverter {
+    type ItemConverter = DocxLineConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(&self, section: Section, writer: &mut dyn Write) -> io::Result<()> {
+        match section {
+            // The heading style starts a new page, so that each chapter begins on its own page.
+            Section::Title(title) => writeln!(
+                writer,
+                "<w:p><w:pPr><w:pStyle w:val=\"Heading1\"/></w:pPr>{}</w:p>",
+                DocxLineItemConverter::run(&title)
+            ),
+            Section::Scene(_, body) => body
+                .into_iter()
+                .try_for_each(|line| self.item_converter().write(line, writer)),
+        }
+    }
+}
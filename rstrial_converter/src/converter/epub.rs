@@ -0,0 +1,3 @@
+pub mod manuscript_converter;
+pub(crate) mod package;
+pub mod section_converter;
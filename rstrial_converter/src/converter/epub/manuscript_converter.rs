@@ -0,0 +1,213 @@
+use std::io::{self, Write};
+
+use log::info;
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{
+    is_published, xml, ConverterOptions, ManuscriptConverter, SectionConverter,
+};
+
+use super::{
+    package::{Book, Chapter},
+    section_converter::EpubSectionConverter,
+};
+
+/// Converts a manuscript into an EPUB 3 package with vertical writing.
+///
+/// Each `# ` title starts a chapter, and the first one becomes the title of the book.
+/// The output is a zip archive, so use `write` instead of `convert`.
+#[derive(Default)]
+pub struct EpubManuscriptConverter {
+    item_converter: EpubSectionConverter,
+}
+
+impl EpubManuscriptConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: EpubSectionConverter::new(options),
+        }
+    }
+}
+
+impl ManuscriptConverter for EpubManuscriptConverter {
+    type ItemConverter = EpubSectionConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(
+        &self,
+        sections: impl IntoIterator<Item = Section>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        let mut book = Book::default();
+        let mut ids = xml::Ids::default();
+        let mut previous_was_scene = false;
+        for mut section in sections.into_iter().filter(is_published) {
+            info!("convert: {:?}", section);
+            let is_scene = matches!(section, Section::Scene(_, _));
+            match &section {
+                Section::Title(title) => {
+                    book.title.get_or_insert_with(|| title.clone());
+                    book.chapters.push(Chapter {
+                        title: Some(title.clone()),
+                        ..Default::default()
+                    });
+                }
+                Section::Scene(_, _) if book.chapters.is_empty() => {
+                    book.chapters.push(Chapter::default())
+                }
+                Section::Scene(_, _) => {
+                    if previous_was_scene {
+                        let body = &mut book.chapters.last_mut().unwrap().body;
+                        writeln!(
+                            body,
+                            "<p class=\"scene-separator\">{}</p>",
+                            xml::escape(&self.options().scene_separator)
+                        )?;
+                    }
+                }
+            }
+            if let Section::Scene(document, _) = &mut section {
+                document.id = document.scene_id().map(|id| ids.unique(&id));
+            }
+            let body = &mut book.chapters.last_mut().unwrap().body;
+            self.item_converter().write(section, body)?;
+            previous_was_scene = is_scene;
+        }
+        // A package needs at least one document in the spine.
+        if book.chapters.is_empty() {
+            book.chapters.push(Chapter::default());
+        }
+        book.write(writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, io::Cursor};
+
+    use quick_xml::{events::Event, Reader};
+    use zip::{CompressionMethod, ZipArchive};
+
+    use crate::converter::test_archive::{self, read};
+
+    use super::*;
+
+    const INPUT: &str = "# 吾輩は猫である\n\n```一\n{吾輩|わがはい}は猫である。名前は{まだ|.}無い。\n```\n```二 {#two}\n「にゃあ」\n```\n```下書き {draft}\n没。\n```\n# 二章\n\n```三\nどこで生れたかとんと見当がつかぬ。\n```\n";
+
+    fn convert(input: &str) -> ZipArchive<Cursor<Vec<u8>>> {
+        test_archive::convert(EpubManuscriptConverter::default(), input)
+    }
+
+    // Collects values of `attribute` on `element` and fails if the XML is not well-formed.
+    fn attributes(xml: &str, element: &str, attribute: &str) -> Vec<String> {
+        let mut reader = Reader::from_str(xml);
+        let mut values = vec![];
+        loop {
+            match reader.read_event() {
+                Ok(Event::Start(tag)) | Ok(Event::Empty(tag)) => {
+                    if tag.name().as_ref() == element.as_bytes() {
+                        if let Some(value) = tag.try_get_attribute(attribute).unwrap() {
+                            values.push(value.unescape_value().unwrap().to_string());
+                        }
+                    }
+                }
+                Ok(Event::Eof) => break,
+                Ok(_) => {}
+                Err(error) => panic!("malformed XML: {:?}\n{}", error, xml),
+            }
+        }
+        values
+    }
+
+    #[test]
+    fn test_mimetype() {
+        let mut archive = convert(INPUT);
+        let mimetype = archive.by_index(0).unwrap();
+        assert_eq!(mimetype.name(), "mimetype");
+        assert_eq!(mimetype.compression(), CompressionMethod::Stored);
+        drop(mimetype);
+        assert_eq!(read(&mut archive, "mimetype"), "application/epub+zip");
+    }
+
+    #[test]
+    fn test_package_structure() {
+        let mut archive = convert(INPUT);
+        let container = read(&mut archive, "META-INF/container.xml");
+        let rootfiles = attributes(&container, "rootfile", "full-path");
+        assert_eq!(rootfiles, vec!["OEBPS/content.opf"]);
+
+        let package = read(&mut archive, "OEBPS/content.opf");
+        assert!(package.contains("<dc:title>吾輩は猫である</dc:title>"));
+        assert!(package.contains("<dc:language>ja</dc:language>"));
+        assert!(package.contains("<meta property=\"dcterms:modified\">"));
+        assert_eq!(
+            attributes(&package, "package", "unique-identifier"),
+            attributes(&package, "dc:identifier", "id")
+        );
+        assert_eq!(
+            attributes(&package, "spine", "page-progression-direction"),
+            vec!["rtl"]
+        );
+        assert_eq!(attributes(&package, "item", "properties"), vec!["nav"]);
+        let ids = attributes(&package, "item", "id")
+            .into_iter()
+            .collect::<HashSet<String>>();
+        let spine = attributes(&package, "itemref", "idref");
+        assert_eq!(spine, vec!["chapter-001", "chapter-002"]);
+        assert!(spine.iter().all(|idref| ids.contains(idref)));
+        for href in attributes(&package, "item", "href") {
+            let content = read(&mut archive, &format!("OEBPS/{}", href));
+            if href.ends_with(".xhtml") {
+                attributes(&content, "html", "xmlns");
+            }
+        }
+
+        let navigation = read(&mut archive, "OEBPS/nav.xhtml");
+        assert_eq!(
+            attributes(&navigation, "a", "href"),
+            vec!["chapter-001.xhtml", "chapter-002.xhtml"]
+        );
+        assert!(read(&mut archive, "OEBPS/style.css").contains("writing-mode: vertical-rl"));
+    }
+
+    #[test]
+    fn test_chapters() {
+        let mut archive = convert(INPUT);
+        let first = read(&mut archive, "OEBPS/chapter-001.xhtml");
+        assert!(first.contains("<title>吾輩は猫である</title>"));
+        assert!(first.contains("<h1>吾輩は猫である</h1>"));
+        assert!(first.contains("<ruby>吾輩<rt>わがはい</rt></ruby>"));
+        assert!(first.contains("<em class=\"sesame\">まだ</em>"));
+        assert!(first
+            .contains("<p class=\"scene-separator\">†</p>\n<section class=\"scene\" id=\"two\">"));
+        assert!(!first.contains("没"));
+        let second = read(&mut archive, "OEBPS/chapter-002.xhtml");
+        assert!(second.contains("<h1>二章</h1>\n<section class=\"scene\" id=\"三\">"));
+        assert!(!second.contains("scene-separator"));
+    }
+
+    #[test]
+    fn test_scene_ids() {
+        let mut archive =
+            convert("```幕間\n一。\n```\n```幕間\n二。\n```\n```1 {#a:b}\n三。\n```\n");
+        let chapter = read(&mut archive, "OEBPS/chapter-001.xhtml");
+        assert_eq!(
+            attributes(&chapter, "section", "id"),
+            vec!["幕間", "幕間-2", "a-b"]
+        );
+    }
+
+    #[test]
+    fn test_empty_manuscript() {
+        let mut archive = convert("");
+        let package = read(&mut archive, "OEBPS/content.opf");
+        assert!(package.contains("<dc:title>無題</dc:title>"));
+        assert_eq!(
+            attributes(&package, "itemref", "idref"),
+            vec!["chapter-001"]
+        );
+    }
+}
@@ -0,0 +1,275 @@
+use std::{
+    io::{self, Cursor, Write},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use zip::{write::FileOptions, CompressionMethod, ZipWriter};
+
+use crate::converter::xml;
+
+const STYLESHEET: &str = r#"html {
+  writing-mode: vertical-rl;
+  -epub-writing-mode: vertical-rl;
+  -webkit-writing-mode: vertical-rl;
+}
+
+body {
+  margin: 0;
+  line-height: 1.75;
+  text-align: justify;
+}
+
+h1 {
+  font-size: 1.4em;
+  margin: 0 0 0 2em;
+}
+
+p {
+  margin: 0;
+}
+
+blockquote {
+  margin: 0;
+  padding-top: 2em;
+}
+
+.scene-separator {
+  margin: 1em 0;
+  text-align: center;
+}
+
+em.sesame {
+  font-style: normal;
+  text-emphasis-style: sesame;
+  -epub-text-emphasis-style: sesame;
+  -webkit-text-emphasis-style: sesame;
+}
+"#;
+
+/// A chapter rendered into one XHTML file. `body` holds the contents of `<body>`.
+#[derive(Default)]
+pub(crate) struct Chapter {
+    pub title: Option<String>,
+    pub body: Vec<u8>,
+}
+
+/// Contents of an EPUB 3 package.
+#[derive(Default)]
+pub(crate) struct Book {
+    pub title: Option<String>,
+    pub chapters: Vec<Chapter>,
+}
+
+impl Book {
+    fn title(&self) -> &str {
+        self.title.as_deref().unwrap_or("無題")
+    }
+
+    // Derives an identifier from the title and the contents since manuscripts have no identifier.
+    // FNV-1a keeps it the same across builds and Rust releases, unlike `DefaultHasher`.
+    fn identifier(&self) -> String {
+        let mut hash = fnv1a(FNV_OFFSET_BASIS, self.title().as_bytes());
+        for chapter in &self.chapters {
+            hash = fnv1a(hash, chapter.title.as_deref().unwrap_or("").as_bytes());
+            hash = fnv1a(hash, &chapter.body);
+        }
+        format!("urn:rstrial:{:016x}", hash)
+    }
+
+    fn chapter_name(index: usize) -> String {
+        format!("chapter-{:03}", index + 1)
+    }
+
+    fn chapter_title<'a>(&'a self, chapter: &'a Chapter) -> &'a str {
+        chapter.title.as_deref().unwrap_or(self.title())
+    }
+
+    /// Writes the package as an OCF zip container.
+    pub fn write(&self, writer: &mut dyn Write) -> io::Result<()> {
+        let stored = FileOptions::default().compression_method(CompressionMethod::Stored);
+        let deflated = FileOptions::default().compression_method(CompressionMethod::Deflated);
+        let mut zip = ZipWriter::new(Cursor::new(vec![]));
+        // `mimetype` must be the first entry and must not be compressed.
+        zip.start_file("mimetype", stored)?;
+        zip.write_all(b"application/epub+zip")?;
+        zip.start_file("META-INF/container.xml", deflated)?;
+        zip.write_all(Self::container().as_bytes())?;
+        zip.start_file("OEBPS/content.opf", deflated)?;
+        zip.write_all(
+            self.package_document(&timestamp(SystemTime::now()))
+                .as_bytes(),
+        )?;
+        zip.start_file("OEBPS/nav.xhtml", deflated)?;
+        zip.write_all(self.navigation().as_bytes())?;
+        zip.start_file("OEBPS/style.css", deflated)?;
+        zip.write_all(STYLESHEET.as_bytes())?;
+        for (index, chapter) in self.chapters.iter().enumerate() {
+            zip.start_file(
+                format!("OEBPS/{}.xhtml", Self::chapter_name(index)),
+                deflated,
+            )?;
+            zip.write_all(Self::xhtml_header(self.chapter_title(chapter)).as_bytes())?;
+            zip.write_all(&chapter.body)?;
+            zip.write_all(b"</body>\n</html>\n")?;
+        }
+        writer.write_all(&zip.finish()?.into_inner())
+    }
+
+    fn container() -> String {
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+<rootfiles>
+<rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/>
+</rootfiles>
+</container>
+"#
+        .to_string()
+    }
+
+    fn package_document(&self, modified: &str) -> String {
+        let manifest = (0..self.chapters.len())
+            .map(|index| {
+                let name = Self::chapter_name(index);
+                format!("<item id=\"{name}\" href=\"{name}.xhtml\" media-type=\"application/xhtml+xml\"/>\n")
+            })
+            .collect::<String>();
+        let spine = (0..self.chapters.len())
+            .map(|index| format!("<itemref idref=\"{}\"/>\n", Self::chapter_name(index)))
+            .collect::<String>();
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<package xmlns="http://www.idpf.org/2007/opf" version="3.0" unique-identifier="book-id" xml:lang="ja">
+<metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+<dc:identifier id="book-id">{}</dc:identifier>
+<dc:title>{}</dc:title>
+<dc:language>ja</dc:language>
+<meta property="dcterms:modified">{}</meta>
+</metadata>
+<manifest>
+<item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+<item id="style" href="style.css" media-type="text/css"/>
+{}</manifest>
+<spine page-progression-direction="rtl">
+{}</spine>
+</package>
+"#,
+            xml::escape(&self.identifier()),
+            xml::escape(self.title()),
+            modified,
+            manifest,
+            spine
+        )
+    }
+
+    fn navigation(&self) -> String {
+        let items = self
+            .chapters
+            .iter()
+            .enumerate()
+            .map(|(index, chapter)| {
+                format!(
+                    "<li><a href=\"{}.xhtml\">{}</a></li>\n",
+                    Self::chapter_name(index),
+                    xml::escape(self.chapter_title(chapter))
+                )
+            })
+            .collect::<String>();
+        format!(
+            "{}<nav epub:type=\"toc\" id=\"toc\">\n<h1>目次</h1>\n<ol>\n{}</ol>\n</nav>\n</body>\n</html>\n",
+            Self::xhtml_header(self.title()),
+            items
+        )
+    }
+
+    fn xhtml_header(title: &str) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE html>
+<html xmlns="http://www.w3.org/1999/xhtml" xmlns:epub="http://www.idpf.org/2007/ops" xml:lang="ja" lang="ja">
+<head>
+<meta charset="UTF-8"/>
+<title>{}</title>
+<link rel="stylesheet" type="text/css" href="style.css"/>
+</head>
+<body>
+"#,
+            xml::escape(title)
+        )
+    }
+}
+
+// Formats a time as `CCYY-MM-DDThh:mm:ssZ` required by `dcterms:modified`.
+fn timestamp(time: SystemTime) -> String {
+    let seconds = time
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0);
+    let (days, seconds) = ((seconds / 86400) as i64, seconds % 86400);
+    // Converts days since the epoch into a civil date of the proleptic Gregorian calendar.
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+// 64-bit FNV-1a hash continued from `hash`.
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_timestamp() {
+        assert_eq!(timestamp(UNIX_EPOCH), "1970-01-01T00:00:00Z");
+        assert_eq!(
+            timestamp(UNIX_EPOCH + Duration::from_secs(1_700_000_000)),
+            "2023-11-14T22:13:20Z"
+        );
+        assert_eq!(
+            timestamp(UNIX_EPOCH + Duration::from_secs(951_782_400)),
+            "2000-02-29T00:00:00Z"
+        );
+    }
+
+    #[test]
+    fn test_identifier() {
+        assert_eq!(fnv1a(FNV_OFFSET_BASIS, b"a"), 0xaf63_dc4c_8601_ec8c);
+        let book = |body: &str| Book {
+            title: Some("吾輩は猫である".to_string()),
+            chapters: vec![Chapter {
+                title: None,
+                body: body.as_bytes().to_vec(),
+            }],
+        };
+        assert_eq!(book("本文").identifier(), book("本文").identifier());
+        assert_ne!(book("本文").identifier(), book("別の本文").identifier());
+    }
+}
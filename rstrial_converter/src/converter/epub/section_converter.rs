@@ -0,0 +1,74 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{
+    html::line_converter::HtmlLineConverter, xml, ConverterOptions, LineConverter, SectionConverter,
+};
+
+/// Converts sections into XHTML, sharing the line converters with HTML.
+pub struct EpubSectionConverter {
+    item_converter: HtmlLineConverter,
+}
+
+impl Default for EpubSectionConverter {
+    fn default() -> Self {
+        Self::new(ConverterOptions::default())
+    }
+}
+
+impl EpubSectionConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: HtmlLineConverter::xhtml(options),
+        }
+    }
+}
+
+impl SectionConverter for EpubSectionConverter {
+    type ItemConverter = HtmlLineConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(&self, section: Section, writer: &mut dyn Write) -> io::Result<()> {
+        match section {
+            Section::Title(title) => writeln!(writer, "<h1>{}</h1>", xml::escape(&title)),
+            Section::Scene(document, body) => {
+                match document.scene_id() {
+                    Some(id) => {
+                        writeln!(writer, "<section class=\"scene\" id=\"{}\">", xml::id(&id))?
+                    }
+                    None => writeln!(writer, "<section class=\"scene\">")?,
+                }
+                for line in body {
+                    self.item_converter().write(line, writer)?;
+                }
+                writeln!(writer, "</section>")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::tokens::{section::Document, Line, LineItem};
+
+    use super::*;
+
+    #[test]
+    fn test_convert_scene() {
+        let mut document = Document::new("第１シーン".to_string(), None, vec![]);
+        document.id = Some("scene-1".to_string());
+        let section = Section::Scene(
+            document,
+            vec![Line::Paragraph(vec![LineItem::Text("本文".to_string())])],
+        );
+        let result = EpubSectionConverter::default().convert(section);
+        assert_eq!(
+            result,
+            "<section class=\"scene\" id=\"scene-1\">\n<p>　本文</p>\n</section>\n"
+        );
+    }
+}
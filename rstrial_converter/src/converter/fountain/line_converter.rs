@@ -0,0 +1,130 @@
+use std::io::{self, Write};
+
+use log::warn;
+use rstrial_parser::tokens::{Line, LineItem};
+
+use crate::converter::{speaker, write_to_string, CommentPolicy, ConverterOptions, LineConverter};
+
+use super::line_item_converter::FountainLineItemConverter;
+
+// Characters starting other elements than action, such as `@` of characters and `.` of scene headings.
+const FORCING_CHARACTERS: [char; 7] = ['!', '@', '.', '>', '~', '=', '#'];
+
+#[derive(Default)]
+pub struct FountainLineConverter {
+    item_converter: FountainLineItemConverter,
+}
+
+impl FountainLineConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: FountainLineItemConverter::new(options),
+        }
+    }
+
+    /// Writes a dialogue block, the character name forced by `@` followed by the dialogue out of `「」`.
+    pub fn write_dialogue(
+        &self,
+        speaker: &str,
+        items: Vec<LineItem>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        writeln!(writer, "@{}", speaker)?;
+        self.write_items(speaker::unquote(items), writer)?;
+        write!(writer, "{}", self.line_separator())
+    }
+
+    // Action is forced by `!` when it would be taken for another element.
+    fn write_action(&self, items: Vec<LineItem>, writer: &mut dyn Write) -> io::Result<()> {
+        let action = write_to_string(|writer| self.write_items(items, writer));
+        if action.starts_with(FORCING_CHARACTERS) {
+            write!(writer, "!")?;
+        }
+        write!(writer, "{}{}", action, self.line_separator())
+    }
+}
+
+impl LineConverter for FountainLineConverter {
+    type ItemConverter = FountainLineItemConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    // Elements are separated by blank lines.
+    fn line_separator(&self) -> String {
+        "\n\n".to_string()
+    }
+
+    fn write_items(&self, items: Vec<LineItem>, writer: &mut dyn Write) -> io::Result<()> {
+        self.write_items_trimmed(items, writer)
+    }
+
+    fn write(&self, line: Line, writer: &mut dyn Write) -> io::Result<()> {
+        match line {
+            Line::Paragraph(items) if items.is_empty() => Ok(()),
+            Line::Paragraph(items) | Line::Quotation(items) => self.write_action(items, writer),
+            Line::Conversation(items) => {
+                warn!(
+                    "dialogue without a speaker is written as action: {:?}",
+                    items
+                );
+                self.write_action(items, writer)
+            }
+            Line::Comment(_) | Line::BlockComment(_)
+                if self.options().comments == CommentPolicy::Drop =>
+            {
+                Ok(())
+            }
+            Line::Comment(comment) => {
+                write!(writer, "[[{}]]{}", comment.trim(), self.line_separator())
+            }
+            // Notes cannot contain blank lines, so block comments go to the boneyard.
+            Line::BlockComment(comment) => write!(
+                writer,
+                "/*\n{}\n*/{}",
+                comment.trim_matches('\n').replace("*/", "* /"),
+                self.line_separator()
+            ),
+            Line::AuthorOnly(_) => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::tokens::line_item::Terminator;
+
+    use super::*;
+
+    #[test]
+    fn test_write_dialogue() {
+        let items = vec![
+            LineItem::Text("「にゃあ".to_string()),
+            LineItem::EndOfSentence(Terminator::Normal("！」".to_string())),
+        ];
+        let result = write_to_string(|writer| {
+            FountainLineConverter::default().write_dialogue("猫", items, writer)
+        });
+        assert_eq!(result, "@猫\nにゃあ！\n\n");
+    }
+
+    #[test]
+    fn test_convert_forced_action() {
+        let line = Line::Paragraph(vec![LineItem::Text(".NETで書く".to_string())]);
+        let result = FountainLineConverter::default().convert(line);
+        assert_eq!(result, "!.NETで書く\n\n");
+    }
+
+    #[test]
+    fn test_convert_block_comment() {
+        let converter = FountainLineConverter::new(ConverterOptions {
+            comments: CommentPolicy::Keep,
+            ..Default::default()
+        });
+        let line = Line::BlockComment("一行目\n\n*/三行目\n".to_string());
+        assert_eq!(converter.convert(line), "/*\n一行目\n\n* /三行目\n*/\n\n");
+        let line = Line::BlockComment("メモ".to_string());
+        assert_eq!(FountainLineConverter::default().convert(line), "");
+    }
+}
@@ -0,0 +1,72 @@
+use rstrial_parser::tokens::{line_item::Terminator, LineItem};
+
+use crate::converter::{CommentPolicy, ConverterOptions, LineItemConverter};
+
+/// Converts items into Fountain.
+///
+/// Fountain has no ruby, so ruby kept by the ruby policy is parenthesized for the cast to read.
+/// Sesame becomes `*italics*`.
+#[derive(Default)]
+pub struct FountainLineItemConverter {
+    options: ConverterOptions,
+}
+
+impl FountainLineItemConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self { options }
+    }
+
+    /// Escapes emphasis markers with backslashes.
+    pub fn escape(text: &str) -> String {
+        let mut escaped = String::with_capacity(text.len());
+        for c in text.chars() {
+            if matches!(c, '\\' | '*' | '_') {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        escaped
+    }
+}
+
+impl LineItemConverter for FountainLineItemConverter {
+    fn options(&self) -> &ConverterOptions {
+        &self.options
+    }
+
+    fn convert(&self, item: LineItem) -> String {
+        match item {
+            LineItem::Text(text) => Self::escape(&text),
+            LineItem::Comma(comma) => comma,
+            LineItem::Comment(comment) => match self.options.comments {
+                CommentPolicy::Keep => format!("[[{}]]", comment.trim()),
+                CommentPolicy::Drop => "".to_string(),
+            },
+            LineItem::TextWithRuby((text, ruby)) => {
+                format!("{}（{}）", Self::escape(&text), Self::escape(&ruby))
+            }
+            LineItem::TextWithSesame((text, _)) => format!("*{}*", Self::escape(&text)),
+            LineItem::EndOfSentence(Terminator::Normal(terminator)) => terminator,
+            LineItem::EndOfSentence(Terminator::Exclamation(terminator)) => {
+                format!("{}　", terminator)
+            }
+            LineItem::EndOfSection(_) => "".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert() {
+        let converter = FountainLineItemConverter::default();
+        let item = LineItem::TextWithRuby(("吾輩".to_string(), "わがはい".to_string()));
+        assert_eq!(converter.convert(item), "吾輩（わがはい）");
+        let item = LineItem::TextWithSesame(("まだ".to_string(), '・'));
+        assert_eq!(converter.convert(item), "*まだ*");
+        let item = LineItem::Text("*_*".to_string());
+        assert_eq!(converter.convert(item), "\\*\\_\\*");
+    }
+}
@@ -0,0 +1,78 @@
+use std::io::{self, Write};
+
+use log::info;
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{is_published, ConverterOptions, ManuscriptConverter, SectionConverter};
+
+use super::section_converter::FountainSectionConverter;
+
+/// Converts a manuscript into a Fountain screenplay for audio-drama scripts.
+///
+/// Conversations become dialogue of the speakers given by `@characters` or `{#@name}`,
+/// and other lines become action. The first title is put on the title page.
+#[derive(Default)]
+pub struct FountainManuscriptConverter {
+    item_converter: FountainSectionConverter,
+}
+
+impl FountainManuscriptConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: FountainSectionConverter::new(options),
+        }
+    }
+}
+
+impl ManuscriptConverter for FountainManuscriptConverter {
+    type ItemConverter = FountainSectionConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(
+        &self,
+        sections: impl IntoIterator<Item = Section>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        let mut sections = sections.into_iter().filter(is_published).peekable();
+        if let Some(Section::Title(title)) = sections.peek() {
+            write!(writer, "Title: {}\n\n", title)?;
+        }
+        let mut previous_was_scene = false;
+        for section in sections {
+            info!("convert: {:?}", section);
+            let is_scene = matches!(section, Section::Scene(_, _));
+            // Scene headings divide titled scenes, so only untitled ones need the separator.
+            if let (Section::Scene(document, _), true) = (&section, previous_was_scene) {
+                let separator = &self.options().scene_separator;
+                if document.title.trim().is_empty() && !separator.is_empty() {
+                    write!(writer, "> {} <\n\n", separator)?;
+                }
+            }
+            self.item_converter().write(section, writer)?;
+            previous_was_scene = is_scene;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::ManuscriptParser;
+
+    use super::*;
+
+    const INPUT: &str = "# 吾輩は猫である\n\n@characters 猫 主人\n```書斎\n{吾輩|わがはい}は猫である。\n「にゃあ」\n主人「うるさい！」\n```\n```\n{#@猫}「名前は{まだ|.}無い」\n「どこ？」\n```\n";
+
+    #[test]
+    fn test_convert() {
+        let result =
+            FountainManuscriptConverter::default().convert(ManuscriptParser::new(INPUT).collect());
+        assert_eq!(
+            result,
+            "Title: 吾輩は猫である\n\n# 吾輩は猫である\n\n.書斎\n\n吾輩（わがはい）は猫である。\n\n「にゃあ」\n\n@主人\nうるさい！\n\n> † <\n\n@猫\n名前は*まだ*無い\n\n「どこ？」\n\n"
+        );
+    }
+}
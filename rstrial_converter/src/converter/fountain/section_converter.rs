@@ -0,0 +1,52 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{
+    speaker::{self, Attributed},
+    ConverterOptions, LineConverter, SectionConverter,
+};
+
+use super::line_converter::FountainLineConverter;
+
+#[derive(Default)]
+pub struct FountainSectionConverter {
+    item_converter: FountainLineConverter,
+}
+
+impl FountainSectionConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: FountainLineConverter::new(options),
+        }
+    }
+}
+
+impl SectionConverter for FountainSectionConverter {
+    type ItemConverter = FountainLineConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    // Titles become sections and scenes start with forced scene headings.
+    fn write(&self, section: Section, writer: &mut dyn Write) -> io::Result<()> {
+        match section {
+            Section::Title(title) => write!(writer, "# {}\n\n", title),
+            Section::Scene(document, body) => {
+                if !document.title.trim().is_empty() {
+                    write!(writer, ".{}\n\n", document.title.trim())?;
+                }
+                for line in body {
+                    match speaker::attribute(line, &document.characters) {
+                        Attributed::Dialogue(speaker, items) => self
+                            .item_converter()
+                            .write_dialogue(&speaker, items, writer)?,
+                        Attributed::Other(line) => self.item_converter().write(line, writer)?,
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::Line;
+
+use crate::converter::{xml, CommentPolicy, ConverterOptions, LineConverter};
+
+use super::line_item_converter::HtmlLineItemConverter;
+
+#[derive(Default)]
+pub struct HtmlLineConverter {
+    item_converter: HtmlLineItemConverter,
+}
+
+impl HtmlLineConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: HtmlLineItemConverter::new(options),
+        }
+    }
+
+    /// Converts into XHTML for EPUB.
+    pub fn xhtml(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: HtmlLineItemConverter::xhtml(options),
+        }
+    }
+}
+
+impl LineConverter for HtmlLineConverter {
+    type ItemConverter = HtmlLineItemConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(&self, line: Line, writer: &mut dyn Write) -> io::Result<()> {
+        match line {
+            // Blank lines in the manuscript are kept as empty paragraphs.
+            Line::Paragraph(items) if items.is_empty() => {
+                if self.item_converter.is_xhtml() {
+                    write!(writer, "<p><br/></p>")?
+                } else {
+                    write!(writer, "<p><br></p>")?
+                }
+            }
+            Line::Paragraph(items) => {
+                write!(writer, "<p>{}", xml::escape(&self.options().indent))?;
+                self.write_items_trimmed(items, writer)?;
+                write!(writer, "</p>")?;
+            }
+            Line::Conversation(items) => {
+                write!(writer, "<p class=\"conversation\">")?;
+                self.write_items_trimmed(items, writer)?;
+                write!(writer, "</p>")?;
+            }
+            Line::Quotation(items) => {
+                write!(writer, "<blockquote><p>")?;
+                self.write_items_trimmed(items, writer)?;
+                write!(writer, "</p></blockquote>")?;
+            }
+            Line::Comment(comment) | Line::BlockComment(comment) => match self.options().comments {
+                CommentPolicy::Keep => write!(writer, "{}", xml::comment(&comment))?,
+                CommentPolicy::Drop => return Ok(()),
+            },
+            Line::AuthorOnly(_) => return Ok(()),
+        }
+        write!(writer, "{}", self.line_separator())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::tokens::{line_item::Terminator, LineItem};
+
+    use super::*;
+
+    #[test]
+    fn test_convert_quotation() {
+        let line = Line::Quotation(vec![
+            LineItem::Text("「どうも".to_string()),
+            LineItem::EndOfSentence(Terminator::Normal("」".to_string())),
+        ]);
+        let result = HtmlLineConverter::default().convert(line);
+        assert_eq!(result, "<blockquote><p>「どうも」</p></blockquote>\n");
+    }
+
+    #[test]
+    fn test_convert_empty_paragraph() {
+        let result = HtmlLineConverter::default().convert(Line::Paragraph(vec![]));
+        assert_eq!(result, "<p><br></p>\n");
+    }
+
+    #[test]
+    fn test_convert_xhtml() {
+        let converter = HtmlLineConverter::xhtml(ConverterOptions::default());
+        let line = Line::Paragraph(vec![
+            LineItem::TextWithRuby(("吾輩".to_string(), "わがはい".to_string())),
+            LineItem::Text("は猫である".to_string()),
+            LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+        ]);
+        assert_eq!(
+            converter.convert(line),
+            "<p>　<ruby>吾輩<rt>わがはい</rt></ruby>は猫である。</p>\n"
+        );
+        assert_eq!(converter.convert(Line::Paragraph(vec![])), "<p><br/></p>\n");
+    }
+
+    #[test]
+    fn test_convert_exclamation_at_end() {
+        let line = Line::Paragraph(vec![
+            LineItem::Text("にゃあ".to_string()),
+            LineItem::EndOfSentence(Terminator::Exclamation("！".to_string())),
+        ]);
+        let result = HtmlLineConverter::default().convert(line);
+        assert_eq!(result, "<p>　にゃあ！</p>\n");
+    }
+}
@@ -0,0 +1,121 @@
+use rstrial_parser::tokens::{line_item::Terminator, LineItem};
+
+use crate::converter::{xml, CommentPolicy, ConverterOptions, LineItemConverter};
+
+#[derive(Default)]
+pub struct HtmlLineItemConverter {
+    options: ConverterOptions,
+    xhtml: bool,
+}
+
+impl HtmlLineItemConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            options,
+            xhtml: false,
+        }
+    }
+
+    /// Converts into XHTML for EPUB, whose readers all support ruby and sesame.
+    pub fn xhtml(options: ConverterOptions) -> Self {
+        Self {
+            options,
+            xhtml: true,
+        }
+    }
+
+    pub(crate) fn is_xhtml(&self) -> bool {
+        self.xhtml
+    }
+}
+
+impl LineItemConverter for HtmlLineItemConverter {
+    fn options(&self) -> &ConverterOptions {
+        &self.options
+    }
+
+    fn convert(&self, item: LineItem) -> String {
+        match item {
+            LineItem::Text(text) => xml::escape(&text),
+            LineItem::Comma(comma) => xml::escape(&comma),
+            LineItem::Comment(comment) => match self.options.comments {
+                CommentPolicy::Keep => xml::comment(&comment),
+                CommentPolicy::Drop => "".to_string(),
+            },
+            LineItem::TextWithRuby((text, ruby)) if self.xhtml => format!(
+                "<ruby>{}<rt>{}</rt></ruby>",
+                xml::escape(&text),
+                xml::escape(&ruby)
+            ),
+            // `rp` shows the ruby in parentheses on browsers without ruby support.
+            LineItem::TextWithRuby((text, ruby)) => format!(
+                "<ruby><rb>{}</rb><rp>（</rp><rt>{}</rt><rp>）</rp></ruby>",
+                xml::escape(&text),
+                xml::escape(&ruby)
+            ),
+            LineItem::EndOfSentence(Terminator::Normal(terminator)) => xml::escape(&terminator),
+            LineItem::EndOfSentence(Terminator::Exclamation(terminator)) => {
+                format!("{}　", xml::escape(&terminator))
+            }
+            LineItem::EndOfSection(_) => "".to_string(),
+            LineItem::TextWithSesame((text, _)) if self.xhtml => {
+                format!("<em class=\"sesame\">{}</em>", xml::escape(&text))
+            }
+            LineItem::TextWithSesame((text, _)) => {
+                format!("<span class=\"sesame\">{}</span>", xml::escape(&text))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_text() {
+        let item = LineItem::Text("<猫>".to_string());
+        let result = HtmlLineItemConverter::default().convert(item);
+        assert_eq!(result, "&lt;猫&gt;");
+    }
+
+    #[test]
+    fn test_convert_rich_text() {
+        let item = LineItem::TextWithRuby(("吾輩".to_string(), "わがはい".to_string()));
+        let result = HtmlLineItemConverter::default().convert(item);
+        assert_eq!(
+            result,
+            "<ruby><rb>吾輩</rb><rp>（</rp><rt>わがはい</rt><rp>）</rp></ruby>"
+        );
+    }
+
+    #[test]
+    fn test_convert_text_with_sesame() {
+        let item = LineItem::TextWithSesame(("まだ".to_string(), '・'));
+        let result = HtmlLineItemConverter::default().convert(item);
+        assert_eq!(result, "<span class=\"sesame\">まだ</span>");
+    }
+
+    #[test]
+    fn test_convert_xhtml() {
+        let converter = HtmlLineItemConverter::xhtml(ConverterOptions::default());
+        let item = LineItem::TextWithRuby(("吾輩".to_string(), "わがはい".to_string()));
+        assert_eq!(
+            converter.convert(item),
+            "<ruby>吾輩<rt>わがはい</rt></ruby>"
+        );
+        let item = LineItem::TextWithSesame(("まだ".to_string(), '・'));
+        assert_eq!(converter.convert(item), "<em class=\"sesame\">まだ</em>");
+    }
+
+    #[test]
+    fn test_convert_comment() {
+        let item = LineItem::Comment("メモ".to_string());
+        let converter = HtmlLineItemConverter::new(ConverterOptions {
+            comments: CommentPolicy::Keep,
+            ..Default::default()
+        });
+        assert_eq!(converter.convert(item.clone()), "<!--メモ-->");
+        assert_eq!(HtmlLineItemConverter::default().convert(item), "");
+    }
+}
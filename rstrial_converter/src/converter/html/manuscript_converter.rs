@@ -0,0 +1,170 @@
+use std::io::{self, Write};
+
+use log::info;
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{
+    is_published, xml, ConverterOptions, ManuscriptConverter, SectionConverter, WritingMode,
+};
+
+use super::section_converter::HtmlSectionConverter;
+
+const COMMON_STYLESHEET: &str = r#"body {
+  margin: 2em;
+  line-height: 1.8;
+  font-family: serif;
+}
+h1 {
+  font-size: 1.4em;
+}
+p {
+  margin: 0;
+}
+ruby > rt {
+  font-size: 0.5em;
+}
+.sesame {
+  text-emphasis: filled sesame;
+  -webkit-text-emphasis: filled sesame;
+}
+.scene-separator {
+  margin: 1em 0;
+  text-align: center;
+}
+"#;
+
+const VERTICAL_STYLESHEET: &str = r#"html {
+  writing-mode: vertical-rl;
+  -webkit-writing-mode: vertical-rl;
+}
+body {
+  height: calc(100vh - 4em);
+}
+blockquote {
+  margin: 0;
+  padding-top: 2em;
+}
+"#;
+
+const HORIZONTAL_STYLESHEET: &str = r#"html {
+  writing-mode: horizontal-tb;
+}
+body {
+  max-width: 40em;
+}
+blockquote {
+  margin: 0;
+  padding-left: 2em;
+}
+"#;
+
+/// Converts a manuscript into a standalone HTML document with an embedded stylesheet
+/// selected by `writing_mode`. Scenes become `<section>` elements with their scene IDs.
+#[derive(Default)]
+pub struct HtmlManuscriptConverter {
+    item_converter: HtmlSectionConverter,
+}
+
+impl HtmlManuscriptConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: HtmlSectionConverter::new(options),
+        }
+    }
+
+    fn write_header(&self, title: &str, writer: &mut dyn Write) -> io::Result<()> {
+        let stylesheet = match self.options().writing_mode {
+            WritingMode::Vertical => VERTICAL_STYLESHEET,
+            WritingMode::Horizontal => HORIZONTAL_STYLESHEET,
+        };
+        write!(
+            writer,
+            "<!DOCTYPE html>\n<html lang=\"ja\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n{}{}</style>\n</head>\n<body>\n",
+            xml::escape(title),
+            COMMON_STYLESHEET,
+            stylesheet
+        )
+    }
+}
+
+impl ManuscriptConverter for HtmlManuscriptConverter {
+    type ItemConverter = HtmlSectionConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(
+        &self,
+        sections: impl IntoIterator<Item = Section>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        let mut sections = sections.into_iter().filter(is_published).peekable();
+        let title = match sections.peek() {
+            Some(Section::Title(title)) => title.clone(),
+            _ => "無題".to_string(),
+        };
+        self.write_header(&title, writer)?;
+        let mut ids = xml::Ids::default();
+        let mut previous_was_scene = false;
+        for mut section in sections {
+            info!("convert: {:?}", section);
+            let is_scene = matches!(section, Section::Scene(_, _));
+            if let (Section::Scene(_, _), true) = (&section, previous_was_scene) {
+                writeln!(
+                    writer,
+                    "<p class=\"scene-separator\">{}</p>",
+                    xml::escape(&self.options().scene_separator)
+                )?;
+            }
+            if let Section::Scene(document, _) = &mut section {
+                document.id = document.scene_id().map(|id| ids.unique(&id));
+            }
+            self.item_converter().write(section, writer)?;
+            previous_was_scene = is_scene;
+        }
+        writeln!(writer, "</body>\n</html>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::ManuscriptParser;
+
+    use super::*;
+
+    const INPUT: &str = "# 吾輩は猫である\n\n```第１シーン\n{吾輩|わがはい}は猫である。名前は{まだ|.}無い。\n```\n```第２シーン {#second}\n「にゃあ」\n```\n";
+
+    #[test]
+    fn test_convert() {
+        let result =
+            HtmlManuscriptConverter::default().convert(ManuscriptParser::new(INPUT).collect());
+        assert!(result.starts_with("<!DOCTYPE html>\n<html lang=\"ja\">\n<head>\n<meta charset=\"utf-8\">\n<title>吾輩は猫である</title>\n"));
+        assert!(result.contains("writing-mode: vertical-rl;"));
+        assert!(result.ends_with(
+            "<body>\n<h1>吾輩は猫である</h1>\n<section id=\"第１シーン\">\n<p>　<ruby><rb>吾輩</rb><rp>（</rp><rt>わがはい</rt><rp>）</rp></ruby>は猫である。名前は<span class=\"sesame\">まだ</span>無い。</p>\n</section>\n<p class=\"scene-separator\">†</p>\n<section id=\"second\">\n<p class=\"conversation\">「にゃあ」</p>\n</section>\n</body>\n</html>\n"
+        ));
+    }
+
+    #[test]
+    fn test_convert_horizontal() {
+        let converter = HtmlManuscriptConverter::new(ConverterOptions {
+            writing_mode: WritingMode::Horizontal,
+            ..Default::default()
+        });
+        let result = converter.convert(ManuscriptParser::new("```\n本文\n```\n").collect());
+        assert!(result.contains("<title>無題</title>"));
+        assert!(result.contains("writing-mode: horizontal-tb;"));
+        assert!(!result.contains("vertical-rl"));
+    }
+
+    #[test]
+    fn test_convert_scene_ids() {
+        let input = "```幕間\n一。\n```\n```幕間\n二。\n```\n```2 {#2}\n三。\n```\n";
+        let result =
+            HtmlManuscriptConverter::default().convert(ManuscriptParser::new(input).collect());
+        assert!(result.contains("<section id=\"幕間\">"));
+        assert!(result.contains("<section id=\"幕間-2\">"));
+        assert!(result.contains("<section id=\"s-2\">"));
+    }
+}
@@ -0,0 +1,44 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{xml, ConverterOptions, LineConverter, SectionConverter};
+
+use super::line_converter::HtmlLineConverter;
+
+#[derive(Default)]
+pub struct HtmlSectionConverter {
+    item_converter: HtmlLineConverter,
+}
+
+impl HtmlSectionConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: HtmlLineConverter::new(options),
+        }
+    }
+}
+
+impl SectionConverter for HtmlSectionConverter {
+    type ItemConverter = HtmlLineConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(&self, section: Section, writer: &mut dyn Write) -> io::Result<()> {
+        match section {
+            Section::Title(title) => writeln!(writer, "<h1>{}</h1>", xml::escape(&title)),
+            Section::Scene(document, body) => {
+                match document.scene_id() {
+                    Some(id) => writeln!(writer, "<section id=\"{}\">", xml::id(&id))?,
+                    None => writeln!(writer, "<section>")?,
+                }
+                for line in body {
+                    self.item_converter().write(line, writer)?;
+                }
+                writeln!(writer, "</section>")
+            }
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::{Line, LineItem};
+
+use crate::converter::{CommentPolicy, ConverterOptions, LineConverter};
+
+use super::line_item_converter::{IndesignLineItemConverter, COMMENT_STYLE};
+
+/// Paragraph style of narration.
+pub const BODY_STYLE: &str = "本文";
+/// Paragraph style of dialogue.
+pub const DIALOGUE_STYLE: &str = "会話";
+/// Paragraph style of quotations.
+pub const QUOTATION_STYLE: &str = "引用";
+
+/// Converts lines into paragraphs with the paragraph style matching the kind of the line.
+#[derive(Default)]
+pub struct IndesignLineConverter {
+    item_converter: IndesignLineItemConverter,
+}
+
+impl IndesignLineConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: IndesignLineItemConverter::new(options),
+        }
+    }
+
+    fn write_paragraph(
+        &self,
+        style: &str,
+        prefix: &str,
+        items: Vec<LineItem>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        write!(
+            writer,
+            "<ParaStyle:{}>{}",
+            style,
+            IndesignLineItemConverter::escape(prefix)
+        )?;
+        self.write_items(items, writer)
+    }
+}
+
+impl LineConverter for IndesignLineConverter {
+    type ItemConverter = IndesignLineItemConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    // Tagged Text for Windows ends paragraphs with CRLF.
+    fn line_separator(&self) -> String {
+        "\r\n".to_string()
+    }
+
+    fn write(&self, line: Line, writer: &mut dyn Write) -> io::Result<()> {
+        match line {
+            Line::Paragraph(items) if items.is_empty() => {
+                self.write_paragraph(BODY_STYLE, "", items, writer)?
+            }
+            Line::Paragraph(items) => {
+                self.write_paragraph(BODY_STYLE, &self.options().indent, items, writer)?
+            }
+            Line::Conversation(items) => self.write_paragraph(DIALOGUE_STYLE, "", items, writer)?,
+            Line::Quotation(items) => self.write_paragraph(QUOTATION_STYLE, "", items, writer)?,
+            Line::Comment(comment) | Line::BlockComment(comment) => {
+                if self.options().comments == CommentPolicy::Keep {
+                    for line in comment.lines() {
+                        write!(
+                            writer,
+                            "<ParaStyle:{}>{}{}",
+                            COMMENT_STYLE,
+                            IndesignLineItemConverter::escape(line),
+                            self.line_separator()
+                        )?;
+                    }
+                }
+                return Ok(());
+            }
+            Line::AuthorOnly(_) => return Ok(()),
+        }
+        write!(writer, "{}", self.line_separator())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::tokens::line_item::Terminator;
+
+    use super::*;
+
+    #[test]
+    fn test_convert_paragraph() {
+        let line = Line::Paragraph(vec![
+            LineItem::Text("吾輩は猫である".to_string()),
+            LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+        ]);
+        let result = IndesignLineConverter::default().convert(line);
+        assert_eq!(result, "<ParaStyle:本文>　吾輩は猫である。\r\n");
+    }
+
+    #[test]
+    fn test_convert_conversation() {
+        let line = Line::Conversation(vec![
+            LineItem::Text("「にゃあ".to_string()),
+            LineItem::EndOfSentence(Terminator::Normal("」".to_string())),
+        ]);
+        let result = IndesignLineConverter::default().convert(line);
+        assert_eq!(result, "<ParaStyle:会話>「にゃあ」\r\n");
+    }
+}
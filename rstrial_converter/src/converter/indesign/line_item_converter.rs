@@ -0,0 +1,136 @@
+use rstrial_parser::tokens::{line_item::Terminator, LineItem};
+
+use crate::converter::{
+    tatechuyoko, CommentPolicy, ConverterOptions, LineItemConverter, WritingMode,
+};
+
+/// Character and paragraph style of kept comments.
+pub const COMMENT_STYLE: &str = "コメント";
+
+/// Converts items into InDesign Tagged Text with ruby, kenten and tate-chu-yoko attributes.
+#[derive(Default)]
+pub struct IndesignLineItemConverter {
+    options: ConverterOptions,
+}
+
+impl IndesignLineItemConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self { options }
+    }
+
+    /// Escapes characters which start or end tags.
+    pub fn escape(text: &str) -> String {
+        let mut escaped = String::with_capacity(text.len());
+        for c in text.chars() {
+            if matches!(c, '\\' | '<' | '>') {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        escaped
+    }
+
+    // Sets short runs of digits or exclamations upright in vertical writing.
+    fn tatechuyoko(
+        &self,
+        text: &str,
+        is_target: fn(char) -> bool,
+        lengths: std::ops::RangeInclusive<usize>,
+    ) -> String {
+        match self.options.writing_mode {
+            WritingMode::Vertical => tatechuyoko::apply(text, is_target, lengths, |run| {
+                format!("<cTCY:1>{}<cTCY:>", run)
+            }),
+            WritingMode::Horizontal => text.to_string(),
+        }
+    }
+
+    fn convert_text(&self, text: &str) -> String {
+        self.tatechuyoko(&Self::escape(text), tatechuyoko::is_digit, 1..=2)
+    }
+}
+
+impl LineItemConverter for IndesignLineItemConverter {
+    fn options(&self) -> &ConverterOptions {
+        &self.options
+    }
+
+    fn convert(&self, item: LineItem) -> String {
+        match item {
+            LineItem::Text(text) => self.convert_text(&text),
+            LineItem::Comma(comma) => Self::escape(&comma),
+            LineItem::Comment(comment) => match self.options.comments {
+                CommentPolicy::Keep => format!(
+                    "<CharStyle:{}>{}<CharStyle:>",
+                    COMMENT_STYLE,
+                    Self::escape(&comment)
+                ),
+                CommentPolicy::Drop => "".to_string(),
+            },
+            // Group ruby over the whole base text.
+            LineItem::TextWithRuby((text, ruby)) => format!(
+                "<cMojiRuby:0><cRuby:1><cRubyString:{}>{}<cMojiRuby:><cRuby:><cRubyString:>",
+                Self::escape(&ruby),
+                Self::escape(&text)
+            ),
+            LineItem::EndOfSentence(Terminator::Normal(terminator)) => Self::escape(&terminator),
+            LineItem::EndOfSentence(Terminator::Exclamation(terminator)) => format!(
+                "{}　",
+                self.tatechuyoko(
+                    &Self::escape(&terminator),
+                    tatechuyoko::is_exclamation,
+                    2..=2
+                )
+            ),
+            LineItem::EndOfSection(_) => "".to_string(),
+            LineItem::TextWithSesame((text, _)) => format!(
+                "<cKentenKind:kentenSesameDot>{}<cKentenKind:>",
+                self.convert_text(&text)
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_text() {
+        let item = LineItem::Text("<12月>".to_string());
+        let result = IndesignLineItemConverter::default().convert(item);
+        assert_eq!(result, "\\<<cTCY:1>12<cTCY:>月\\>");
+        let converter = IndesignLineItemConverter::new(ConverterOptions {
+            writing_mode: WritingMode::Horizontal,
+            ..Default::default()
+        });
+        assert_eq!(
+            converter.convert(LineItem::Text("12月".to_string())),
+            "12月"
+        );
+    }
+
+    #[test]
+    fn test_convert_rich_text() {
+        let item = LineItem::TextWithRuby(("吾輩".to_string(), "わがはい".to_string()));
+        let result = IndesignLineItemConverter::default().convert(item);
+        assert_eq!(
+            result,
+            "<cMojiRuby:0><cRuby:1><cRubyString:わがはい>吾輩<cMojiRuby:><cRuby:><cRubyString:>"
+        );
+    }
+
+    #[test]
+    fn test_convert_text_with_sesame() {
+        let item = LineItem::TextWithSesame(("まだ".to_string(), '・'));
+        let result = IndesignLineItemConverter::default().convert(item);
+        assert_eq!(result, "<cKentenKind:kentenSesameDot>まだ<cKentenKind:>");
+    }
+
+    #[test]
+    fn test_convert_exclamation() {
+        let item = LineItem::EndOfSentence(Terminator::Exclamation("！？".to_string()));
+        let result = IndesignLineItemConverter::default().convert(item);
+        assert_eq!(result, "<cTCY:1>!?<cTCY:>　");
+    }
+}
@@ -0,0 +1,151 @@
+use std::io::{self, Write};
+
+use log::info;
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{
+    is_published, write_to_string, ConverterOptions, LineConverter, ManuscriptConverter,
+    SectionConverter,
+};
+
+use super::{
+    line_converter::{BODY_STYLE, DIALOGUE_STYLE, QUOTATION_STYLE},
+    line_item_converter::{IndesignLineItemConverter, COMMENT_STYLE},
+    section_converter::{IndesignSectionConverter, HEADING_STYLE},
+};
+
+/// Paragraph style of scene separators.
+pub const SEPARATOR_STYLE: &str = "場面転換";
+
+/// Converts a manuscript into InDesign Tagged Text to be placed into a text frame.
+///
+/// Paragraph styles are defined with empty attributes so that an InDesign template
+/// with the same style names decides the layout. `write` and `convert` produce the text
+/// as UTF-8 like other converters, and `write_utf16` encodes it into UTF-16LE with a BOM
+/// as `<UNICODE-WIN>` requires for files placed into InDesign.
+#[derive(Default)]
+pub struct IndesignManuscriptConverter {
+    item_converter: IndesignSectionConverter,
+}
+
+impl IndesignManuscriptConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: IndesignSectionConverter::new(options),
+        }
+    }
+
+    fn write_header(&self, writer: &mut dyn Write) -> io::Result<()> {
+        let separator = self.item_converter().item_converter().line_separator();
+        write!(
+            writer,
+            "<UNICODE-WIN>{0}<Version:13><FeatureSet:InDesign-Japanese><ColorTable:=<Black:COLOR:CMYK:Process:0,0,0,1>>{0}",
+            separator
+        )?;
+        write!(writer, "<DefineCharStyle:{}=>{}", COMMENT_STYLE, separator)?;
+        for style in [
+            BODY_STYLE,
+            DIALOGUE_STYLE,
+            QUOTATION_STYLE,
+            HEADING_STYLE,
+            SEPARATOR_STYLE,
+            COMMENT_STYLE,
+        ] {
+            write!(
+                writer,
+                "<DefineParaStyle:{}=<Nextstyle:{}>>{}",
+                style, style, separator
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Writes the text encoded into UTF-16LE with a BOM, which InDesign expects of `<UNICODE-WIN>`.
+    pub fn write_utf16(
+        &self,
+        sections: impl IntoIterator<Item = Section>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        let text = write_to_string(|writer| self.write(sections, writer));
+        let mut encoded = Vec::with_capacity(text.len() * 2 + 2);
+        encoded.extend_from_slice(&[0xff, 0xfe]);
+        for unit in text.encode_utf16() {
+            encoded.extend_from_slice(&unit.to_le_bytes());
+        }
+        writer.write_all(&encoded)
+    }
+}
+
+impl ManuscriptConverter for IndesignManuscriptConverter {
+    type ItemConverter = IndesignSectionConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(
+        &self,
+        sections: impl IntoIterator<Item = Section>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        self.write_header(writer)?;
+        let mut previous_was_scene = false;
+        for section in sections.into_iter().filter(is_published) {
+            info!("convert: {:?}", section);
+            let is_scene = matches!(section, Section::Scene(_, _));
+            if let (Section::Scene(_, _), true) = (&section, previous_was_scene) {
+                write!(
+                    writer,
+                    "<ParaStyle:{}>{}{}",
+                    SEPARATOR_STYLE,
+                    IndesignLineItemConverter::escape(&self.options().scene_separator),
+                    self.item_converter().item_converter().line_separator()
+                )?;
+            }
+            self.item_converter().write(section, writer)?;
+            previous_was_scene = is_scene;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::ManuscriptParser;
+
+    use super::*;
+
+    const INPUT: &str = "# 吾輩は猫である\n\n```\n{吾輩|わがはい}は猫である。\n```\n```\n「名前は{まだ|.}無い」\n```\n";
+
+    #[test]
+    fn test_convert() {
+        let result =
+            IndesignManuscriptConverter::default().convert(ManuscriptParser::new(INPUT).collect());
+        let (header, body) = result.split_once("<ParaStyle:見出し>").unwrap();
+        assert!(header.starts_with("<UNICODE-WIN>\r\n"));
+        assert!(header.contains("<DefineCharStyle:コメント=>\r\n"));
+        assert!(header.contains("<DefineParaStyle:会話=<Nextstyle:会話>>\r\n"));
+        assert_eq!(
+            body,
+            "吾輩は猫である\r\n<ParaStyle:本文>　<cMojiRuby:0><cRuby:1><cRubyString:わがはい>吾輩<cMojiRuby:><cRuby:><cRubyString:>は猫である。\r\n<ParaStyle:場面転換>†\r\n<ParaStyle:会話>「名前は<cKentenKind:kentenSesameDot>まだ<cKentenKind:>無い」\r\n"
+        );
+    }
+
+    #[test]
+    fn test_write_utf16() {
+        let mut output = vec![];
+        IndesignManuscriptConverter::default()
+            .write_utf16(ManuscriptParser::new(INPUT), &mut output)
+            .unwrap();
+        assert_eq!(&output[..2], &[0xff, 0xfe]);
+        let units = output[2..]
+            .chunks(2)
+            .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+            .collect::<Vec<u16>>();
+        let text = String::from_utf16(&units).unwrap();
+        assert_eq!(
+            text,
+            IndesignManuscriptConverter::default().convert(ManuscriptParser::new(INPUT).collect())
+        );
+    }
+}
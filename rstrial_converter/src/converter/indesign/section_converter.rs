@@ -0,0 +1,48 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{ConverterOptions, LineConverter, SectionConverter};
+
+use super::{
+    line_converter::IndesignLineConverter, line_item_converter::IndesignLineItemConverter,
+};
+
+/// Paragraph style of titles.
+pub const HEADING_STYLE: &str = "見出し";
+
+#[derive(Default)]
+pub struct IndesignSectionConverter {
+    item_converter: IndesignLineConverter,
+}
+
+impl IndesignSectionConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: IndesignLineConverter::new(options),
+        }
+    }
+}
+
+impl SectionConverter for IndesignSectionConverter {
+    type ItemConverter = IndesignLineConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(&self, section: Section, writer: &mut dyn Write) -> io::Result<()> {
+        match section {
+            Section::Title(title) => write!(
+                writer,
+                "<ParaStyle:{}>{}{}",
+                HEADING_STYLE,
+                IndesignLineItemConverter::escape(&title),
+                self.item_converter().line_separator()
+            ),
+            Section::Scene(_, body) => body
+                .into_iter()
+                .try_for_each(|line| self.item_converter().write(line, writer)),
+        }
+    }
+}
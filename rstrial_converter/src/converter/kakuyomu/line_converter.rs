@@ -0,0 +1,73 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::{Line, LineItem};
+
+use crate::converter::{ConverterOptions, LineConverter};
+
+use super::line_item_converter::KakuyomuLineItemConverter;
+
+#[derive(Default)]
+pub struct KakuyomuLineConverter {
+    item_converter: KakuyomuLineItemConverter,
+}
+
+impl KakuyomuLineConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: KakuyomuLineItemConverter::new(options),
+        }
+    }
+}
+
+impl LineConverter for KakuyomuLineConverter {
+    type ItemConverter = KakuyomuLineItemConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write_items(&self, items: Vec<LineItem>, writer: &mut dyn Write) -> io::Result<()> {
+        self.write_items_trimmed(items, writer)
+    }
+
+    fn write(&self, line: Line, writer: &mut dyn Write) -> io::Result<()> {
+        match line {
+            Line::Paragraph(items) => {
+                write!(writer, "{}", self.options().indent)?;
+                self.write_items(items, writer)?;
+            }
+            Line::Conversation(items) | Line::Quotation(items) => {
+                self.write_items(items, writer)?;
+            }
+            Line::Comment(_) | Line::BlockComment(_) | Line::AuthorOnly(_) => return Ok(()),
+        }
+        write!(writer, "{}", self.line_separator())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::tokens::line_item::Terminator;
+
+    use super::*;
+
+    #[test]
+    fn test_convert_paragraph() {
+        let line = Line::Paragraph(vec![
+            LineItem::Text("名前は".to_string()),
+            LineItem::TextWithSesame(("まだ".to_string(), '・')),
+            LineItem::Text("無い".to_string()),
+            LineItem::EndOfSentence(Terminator::Exclamation("！".to_string())),
+            LineItem::Text("本当に".to_string()),
+            LineItem::EndOfSentence(Terminator::Exclamation("！".to_string())),
+        ]);
+        let result = KakuyomuLineConverter::default().convert(line);
+        assert_eq!(result, "　名前は《《まだ》》無い！　本当に！\n");
+    }
+
+    #[test]
+    fn test_convert_comment() {
+        let result = KakuyomuLineConverter::default().convert(Line::Comment("メモ".to_string()));
+        assert_eq!(result, "");
+    }
+}
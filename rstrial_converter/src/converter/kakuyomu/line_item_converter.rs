@@ -0,0 +1,95 @@
+use log::warn;
+use rstrial_parser::tokens::{line_item::Terminator, LineItem};
+
+use crate::converter::{ConverterOptions, LineItemConverter};
+
+// Kakuyomu renders ruby only for base text up to this length.
+pub const KAKUYOMU_MAX_RUBY_BASE_LENGTH: usize = 20;
+
+/// Converts items into Kakuyomu notations. Comments are dropped since Kakuyomu has no notation for them.
+#[derive(Default)]
+pub struct KakuyomuLineItemConverter {
+    options: ConverterOptions,
+}
+
+impl KakuyomuLineItemConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self { options }
+    }
+
+    // Escapes `《` which would be taken as ruby or emphasis.
+    fn escape(text: &str) -> String {
+        text.replace('《', "|《")
+    }
+}
+
+impl LineItemConverter for KakuyomuLineItemConverter {
+    fn options(&self) -> &ConverterOptions {
+        &self.options
+    }
+
+    fn convert(&self, item: LineItem) -> String {
+        match item {
+            LineItem::Text(text) => Self::escape(&text),
+            LineItem::Comma(comma) => comma,
+            LineItem::Comment(_) => "".to_string(),
+            LineItem::TextWithRuby((text, ruby))
+                if text.chars().count() > KAKUYOMU_MAX_RUBY_BASE_LENGTH =>
+            {
+                warn!(
+                    "ruby base longer than {} characters is parenthesized: {}",
+                    KAKUYOMU_MAX_RUBY_BASE_LENGTH, text
+                );
+                format!("{}（{}）", Self::escape(&text), ruby)
+            }
+            LineItem::TextWithRuby((text, ruby)) => format!("|{text}《{ruby}》"),
+            LineItem::EndOfSentence(Terminator::Normal(terminator)) => terminator,
+            LineItem::EndOfSentence(Terminator::Exclamation(terminator)) => {
+                format!("{}　", terminator)
+            }
+            LineItem::EndOfSection(_) => "".to_string(),
+            LineItem::TextWithSesame((text, _)) => format!("《《{text}》》"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_text() {
+        let item = LineItem::Text("無い《仮》".to_string());
+        let result = KakuyomuLineItemConverter::default().convert(item);
+        assert_eq!(result, "無い|《仮》");
+    }
+
+    #[test]
+    fn test_convert_rich_text() {
+        let item = LineItem::TextWithRuby(("吾輩".to_string(), "わがはい".to_string()));
+        let result = KakuyomuLineItemConverter::default().convert(item);
+        assert_eq!(result, "|吾輩《わがはい》");
+    }
+
+    #[test]
+    fn test_convert_long_ruby_base() {
+        let base = "長".repeat(KAKUYOMU_MAX_RUBY_BASE_LENGTH + 1);
+        let item = LineItem::TextWithRuby((base.clone(), "ながい".to_string()));
+        let result = KakuyomuLineItemConverter::default().convert(item);
+        assert_eq!(result, format!("{base}（ながい）"));
+    }
+
+    #[test]
+    fn test_convert_text_with_sesame() {
+        let item = LineItem::TextWithSesame(("まだ".to_string(), '・'));
+        let result = KakuyomuLineItemConverter::default().convert(item);
+        assert_eq!(result, "《《まだ》》");
+    }
+
+    #[test]
+    fn test_convert_comment() {
+        let item = LineItem::Comment("メモ".to_string());
+        let result = KakuyomuLineItemConverter::default().convert(item);
+        assert_eq!(result, "");
+    }
+}
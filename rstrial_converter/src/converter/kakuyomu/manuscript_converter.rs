@@ -0,0 +1,70 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{ConverterOptions, ManuscriptConverter};
+
+use super::section_converter::KakuyomuSectionConverter;
+
+/// Converts a manuscript into the body of a Kakuyomu episode.
+///
+/// Titles are left out since they are entered separately from the body,
+/// and scenes are separated by the scene separator.
+#[derive(Default)]
+pub struct KakuyomuManuscriptConverter {
+    item_converter: KakuyomuSectionConverter,
+}
+
+impl KakuyomuManuscriptConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: KakuyomuSectionConverter::new(options),
+        }
+    }
+}
+
+impl ManuscriptConverter for KakuyomuManuscriptConverter {
+    type ItemConverter = KakuyomuSectionConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(
+        &self,
+        sections: impl IntoIterator<Item = Section>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        self.write_scenes(sections, writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::{ManuscriptParser, Platform, WebNovelParser};
+
+    use crate::converter::rstrial::manuscript_converter::RstrialManuscriptConverter;
+
+    use super::*;
+
+    #[test]
+    fn test_convert() {
+        let input = "# 吾輩は猫である\n\n```第一話\n{吾輩|わがはい}は猫である。名前は{まだ|.}無い！\n// メモ\n```\n```\n「にゃあ」\n```\n";
+        let result =
+            KakuyomuManuscriptConverter::default().convert(ManuscriptParser::new(input).collect());
+        assert_eq!(
+            result,
+            "　|吾輩《わがはい》は猫である。名前は《《まだ》》無い！\n\n†\n\n「にゃあ」\n"
+        );
+    }
+
+    #[test]
+    fn test_convert_and_import() {
+        let input = "```\n{吾輩|わがはい}は猫である。名前は{まだ|.}無い《仮》！\n```\n";
+        let kakuyomu =
+            KakuyomuManuscriptConverter::default().convert(ManuscriptParser::new(input).collect());
+        let result = RstrialManuscriptConverter::default()
+            .convert(WebNovelParser::new(&kakuyomu, Platform::Kakuyomu).collect());
+        assert_eq!(result, input);
+    }
+}
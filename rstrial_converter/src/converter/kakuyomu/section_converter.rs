@@ -0,0 +1,24 @@
+use crate::converter::{ConverterOptions, SectionConverter};
+
+use super::line_converter::KakuyomuLineConverter;
+
+#[derive(Default)]
+pub struct KakuyomuSectionConverter {
+    item_converter: KakuyomuLineConverter,
+}
+
+impl KakuyomuSectionConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: KakuyomuLineConverter::new(options),
+        }
+    }
+}
+
+impl SectionConverter for KakuyomuSectionConverter {
+    type ItemConverter = KakuyomuLineConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+}
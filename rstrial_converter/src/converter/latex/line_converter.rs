@@ -0,0 +1,87 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::Line;
+
+use crate::converter::{CommentPolicy, ConverterOptions, LineConverter};
+
+use super::line_item_converter::LatexLineItemConverter;
+
+/// Converts lines into LaTeX paragraphs. Paragraphs are indented by the document class,
+/// so the `indent` option is not used.
+#[derive(Default)]
+pub struct LatexLineConverter {
+    item_converter: LatexLineItemConverter,
+}
+
+impl LatexLineConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: LatexLineItemConverter::new(options),
+        }
+    }
+}
+
+impl LineConverter for LatexLineConverter {
+    type ItemConverter = LatexLineItemConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn line_separator(&self) -> String {
+        "\n\n".to_string()
+    }
+
+    fn write(&self, line: Line, writer: &mut dyn Write) -> io::Result<()> {
+        match line {
+            Line::Paragraph(items) if items.is_empty() => write!(writer, "\\mbox{{}}")?,
+            Line::Paragraph(items) => self.write_items(items, writer)?,
+            Line::Conversation(items) => {
+                write!(writer, "\\noindent ")?;
+                self.write_items(items, writer)?;
+            }
+            Line::Quotation(items) => {
+                writeln!(writer, "\\begin{{quote}}")?;
+                self.write_items(items, writer)?;
+                write!(writer, "\n\\end{{quote}}")?;
+            }
+            Line::Comment(comment) | Line::BlockComment(comment) => {
+                if self.options().comments == CommentPolicy::Keep {
+                    for line in comment.lines() {
+                        writeln!(writer, "%{}", line)?;
+                    }
+                }
+                return Ok(());
+            }
+            Line::AuthorOnly(_) => return Ok(()),
+        }
+        write!(writer, "{}", self.line_separator())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::tokens::{line_item::Terminator, LineItem};
+
+    use super::*;
+
+    #[test]
+    fn test_convert_conversation() {
+        let line = Line::Conversation(vec![
+            LineItem::Text("「にゃあ".to_string()),
+            LineItem::EndOfSentence(Terminator::Normal("」".to_string())),
+        ]);
+        let result = LatexLineConverter::default().convert(line);
+        assert_eq!(result, "\\noindent 「にゃあ」\n\n");
+    }
+
+    #[test]
+    fn test_convert_block_comment() {
+        let converter = LatexLineConverter::new(ConverterOptions {
+            comments: CommentPolicy::Keep,
+            ..Default::default()
+        });
+        let result = converter.convert(Line::BlockComment("一行目\n二行目".to_string()));
+        assert_eq!(result, "%一行目\n%二行目\n");
+    }
+}
@@ -0,0 +1,146 @@
+use rstrial_parser::tokens::{line_item::Terminator, LineItem};
+
+use crate::converter::{
+    tatechuyoko, CommentPolicy, ConverterOptions, LineItemConverter, WritingMode,
+};
+
+/// Converts items into LaTeX for jlreq and pxrubrica.
+#[derive(Default)]
+pub struct LatexLineItemConverter {
+    options: ConverterOptions,
+}
+
+impl LatexLineItemConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self { options }
+    }
+
+    /// Escapes characters which have special meanings in LaTeX.
+    pub fn escape(text: &str) -> String {
+        let mut escaped = String::with_capacity(text.len());
+        for c in text.chars() {
+            match c {
+                '\\' => escaped.push_str("\\textbackslash{}"),
+                '{' | '}' | '$' | '&' | '#' | '_' | '%' => {
+                    escaped.push('\\');
+                    escaped.push(c);
+                }
+                '^' => escaped.push_str("\\textasciicircum{}"),
+                '~' => escaped.push_str("\\textasciitilde{}"),
+                c => escaped.push(c),
+            }
+        }
+        escaped
+    }
+
+    // Sets short runs of digits or exclamations upright in vertical writing.
+    fn tatechuyoko(
+        &self,
+        text: &str,
+        is_target: fn(char) -> bool,
+        lengths: std::ops::RangeInclusive<usize>,
+    ) -> String {
+        match self.options.writing_mode {
+            WritingMode::Vertical => tatechuyoko::apply(text, is_target, lengths, |run| {
+                format!("\\tatechuyoko{{{}}}", run)
+            }),
+            WritingMode::Horizontal => text.to_string(),
+        }
+    }
+
+    fn convert_text(&self, text: &str) -> String {
+        self.tatechuyoko(&Self::escape(text), tatechuyoko::is_digit, 1..=2)
+    }
+}
+
+impl LineItemConverter for LatexLineItemConverter {
+    fn options(&self) -> &ConverterOptions {
+        &self.options
+    }
+
+    fn convert(&self, item: LineItem) -> String {
+        match item {
+            LineItem::Text(text) => self.convert_text(&text),
+            LineItem::Comma(comma) => Self::escape(&comma),
+            // `%` also swallows the line break, so the paragraph continues on the next line.
+            LineItem::Comment(comment) => match self.options.comments {
+                CommentPolicy::Keep => format!("%{}\n", comment),
+                CommentPolicy::Drop => "".to_string(),
+            },
+            LineItem::TextWithRuby((text, ruby)) => format!(
+                "\\ruby{{{}}}{{{}}}",
+                Self::escape(&text),
+                Self::escape(&ruby)
+            ),
+            LineItem::EndOfSentence(Terminator::Normal(terminator)) => Self::escape(&terminator),
+            LineItem::EndOfSentence(Terminator::Exclamation(terminator)) => format!(
+                "{}　",
+                self.tatechuyoko(
+                    &Self::escape(&terminator),
+                    tatechuyoko::is_exclamation,
+                    2..=2
+                )
+            ),
+            LineItem::EndOfSection(_) => "".to_string(),
+            LineItem::TextWithSesame((text, _)) => {
+                format!("\\kenten{{{}}}", self.convert_text(&text))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_text() {
+        let item = LineItem::Text("100% の 12 月 {#}".to_string());
+        let result = LatexLineItemConverter::default().convert(item);
+        assert_eq!(result, "100\\% の \\tatechuyoko{12} 月 \\{\\#\\}");
+    }
+
+    #[test]
+    fn test_convert_horizontal_text() {
+        let item = LineItem::Text("12月".to_string());
+        let converter = LatexLineItemConverter::new(ConverterOptions {
+            writing_mode: WritingMode::Horizontal,
+            ..Default::default()
+        });
+        assert_eq!(converter.convert(item), "12月");
+    }
+
+    #[test]
+    fn test_convert_rich_text() {
+        let item = LineItem::TextWithRuby(("吾輩".to_string(), "わがはい".to_string()));
+        let result = LatexLineItemConverter::default().convert(item);
+        assert_eq!(result, "\\ruby{吾輩}{わがはい}");
+    }
+
+    #[test]
+    fn test_convert_text_with_sesame() {
+        let item = LineItem::TextWithSesame(("まだ".to_string(), '・'));
+        let result = LatexLineItemConverter::default().convert(item);
+        assert_eq!(result, "\\kenten{まだ}");
+    }
+
+    #[test]
+    fn test_convert_exclamation() {
+        let item = LineItem::EndOfSentence(Terminator::Exclamation("！？".to_string()));
+        let result = LatexLineItemConverter::default().convert(item);
+        assert_eq!(result, "\\tatechuyoko{!?}　");
+        let item = LineItem::EndOfSentence(Terminator::Exclamation("！".to_string()));
+        let result = LatexLineItemConverter::default().convert(item);
+        assert_eq!(result, "！　");
+    }
+
+    #[test]
+    fn test_convert_comment() {
+        let item = LineItem::Comment("メモ".to_string());
+        let converter = LatexLineItemConverter::new(ConverterOptions {
+            comments: CommentPolicy::Keep,
+            ..Default::default()
+        });
+        assert_eq!(converter.convert(item), "%メモ\n");
+    }
+}
@@ -0,0 +1,101 @@
+use std::io::{self, Write};
+
+use log::info;
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{
+    is_published, ConverterOptions, ManuscriptConverter, SectionConverter, WritingMode,
+};
+
+use super::{
+    line_item_converter::LatexLineItemConverter, section_converter::LatexSectionConverter,
+};
+
+/// Converts a manuscript into a complete LaTeX document for upLaTeX or LuaLaTeX.
+///
+/// The document uses the jlreq class for Japanese typesetting and pxrubrica for ruby and kenten.
+/// Titles become unnumbered chapters.
+#[derive(Default)]
+pub struct LatexManuscriptConverter {
+    item_converter: LatexSectionConverter,
+}
+
+impl LatexManuscriptConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: LatexSectionConverter::new(options),
+        }
+    }
+
+    fn write_preamble(&self, writer: &mut dyn Write) -> io::Result<()> {
+        let class_options = match self.options().writing_mode {
+            WritingMode::Vertical => "tate,book",
+            WritingMode::Horizontal => "book",
+        };
+        writeln!(writer, "\\documentclass[{}]{{jlreq}}", class_options)?;
+        writeln!(writer, "\\usepackage{{pxrubrica}}")?;
+        writeln!(writer, "\\begin{{document}}\n")
+    }
+}
+
+impl ManuscriptConverter for LatexManuscriptConverter {
+    type ItemConverter = LatexSectionConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(
+        &self,
+        sections: impl IntoIterator<Item = Section>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        self.write_preamble(writer)?;
+        let mut previous_was_scene = false;
+        for section in sections.into_iter().filter(is_published) {
+            info!("convert: {:?}", section);
+            let is_scene = matches!(section, Section::Scene(_, _));
+            if let (Section::Scene(_, _), true) = (&section, previous_was_scene) {
+                writeln!(
+                    writer,
+                    "\\begin{{center}}\n{}\n\\end{{center}}\n",
+                    LatexLineItemConverter::escape(&self.options().scene_separator)
+                )?;
+            }
+            self.item_converter().write(section, writer)?;
+            previous_was_scene = is_scene;
+        }
+        writeln!(writer, "\\end{{document}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::ManuscriptParser;
+
+    use super::*;
+
+    #[test]
+    fn test_convert_golden_file() {
+        let input = include_str!("../../../../test/fixtures/cat.txt");
+        let expected = include_str!("../../../../test/fixtures/cat.tex");
+        let result =
+            LatexManuscriptConverter::default().convert(ManuscriptParser::new(input).collect());
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_convert_with_options() {
+        let input = "```\n本文。\n```\n```\n本文。\n```\n";
+        let converter = LatexManuscriptConverter::new(ConverterOptions {
+            scene_separator: "＊　＊　＊".to_string(),
+            writing_mode: WritingMode::Horizontal,
+            ..Default::default()
+        });
+        let result = converter.convert(ManuscriptParser::new(input).collect());
+        assert_eq!(
+            result,
+            "\\documentclass[book]{jlreq}\n\\usepackage{pxrubrica}\n\\begin{document}\n\n本文。\n\n\\begin{center}\n＊　＊　＊\n\\end{center}\n\n本文。\n\n\\end{document}\n"
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{ConverterOptions, LineConverter, SectionConverter};
+
+use super::{line_converter::LatexLineConverter, line_item_converter::LatexLineItemConverter};
+
+#[derive(Default)]
+pub struct LatexSectionConverter {
+    item_converter: LatexLineConverter,
+}
+
+impl LatexSectionConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: LatexLineConverter::new(options),
+        }
+    }
+}
+
+impl SectionConverter for LatexSectionConverter {
+    type ItemConverter = LatexLineConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(&self, section: Section, writer: &mut dyn Write) -> io::Result<()> {
+        match section {
+            Section::Title(title) => writeln!(
+                writer,
+                "\\chapter*{{{}}}\n",
+                LatexLineItemConverter::escape(&title)
+            ),
+            Section::Scene(_, body) => body
+                .into_iter()
+                .try_for_each(|line| self.item_converter().write(line, writer)),
+        }
+    }
+}
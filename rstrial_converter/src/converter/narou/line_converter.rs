@@ -0,0 +1,101 @@
+use std::io::{self, Write};
+
+use rstrial_parser::{
+    is_kanji,
+    tokens::{Line, LineItem},
+};
+
+use crate::converter::{ConverterOptions, LineConverter, LineItemConverter};
+
+use super::line_item_converter::NarouLineItemConverter;
+
+#[derive(Default)]
+pub struct NarouLineConverter {
+    item_converter: NarouLineItemConverter,
+}
+
+impl NarouLineConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: NarouLineItemConverter::new(options),
+        }
+    }
+}
+
+impl LineConverter for NarouLineConverter {
+    type ItemConverter = NarouLineItemConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    // Omits `|` before ruby on kanji unless the preceding character is kanji too.
+    fn write_items(&self, items: Vec<LineItem>, writer: &mut dyn Write) -> io::Result<()> {
+        let items = self
+            .trim_items(items)
+            .into_iter()
+            .flat_map(|item| match item {
+                LineItem::TextWithSesame((text, _)) => text
+                    .chars()
+                    .map(|c| LineItem::TextWithRuby((c.to_string(), "・".to_string())))
+                    .collect(),
+                item => vec![item],
+            });
+        let mut previous = None;
+        for item in items {
+            let converted = match item {
+                LineItem::TextWithRuby((text, ruby))
+                    if NarouLineItemConverter::is_within_limits(&text, &ruby)
+                        && text.chars().all(is_kanji)
+                        && !previous.is_some_and(is_kanji) =>
+                {
+                    format!("{text}《{ruby}》")
+                }
+                item => self.item_converter().convert(item),
+            };
+            previous = converted.chars().last().or(previous);
+            write!(writer, "{}", converted)?;
+        }
+        Ok(())
+    }
+
+    fn write(&self, line: Line, writer: &mut dyn Write) -> io::Result<()> {
+        match line {
+            Line::Paragraph(items) => {
+                write!(writer, "{}", self.options().indent)?;
+                self.write_items(items, writer)?;
+            }
+            Line::Conversation(items) | Line::Quotation(items) => {
+                self.write_items(items, writer)?;
+            }
+            Line::Comment(_) | Line::BlockComment(_) | Line::AuthorOnly(_) => return Ok(()),
+        }
+        write!(writer, "{}", self.line_separator())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::tokens::line_item::Terminator;
+
+    use super::*;
+
+    #[test]
+    fn test_convert_paragraph() {
+        let line = Line::Paragraph(vec![
+            LineItem::TextWithRuby(("吾輩".to_string(), "わがはい".to_string())),
+            LineItem::Text("は三毛".to_string()),
+            LineItem::TextWithRuby(("猫".to_string(), "ねこ".to_string())),
+            LineItem::TextWithRuby(("又".to_string(), "また".to_string())),
+            LineItem::Text("は".to_string()),
+            LineItem::TextWithRuby(("ミケ".to_string(), "みけ".to_string())),
+            LineItem::TextWithSesame(("猫だ".to_string(), '・')),
+            LineItem::EndOfSentence(Terminator::Exclamation("！".to_string())),
+        ]);
+        let result = NarouLineConverter::default().convert(line);
+        assert_eq!(
+            result,
+            "　吾輩《わがはい》は三毛|猫《ねこ》又《また》は|ミケ《みけ》猫《・》|だ《・》！\n"
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use log::warn;
+use rstrial_parser::{
+    tokens::{line_item::Terminator, LineItem},
+    NAROU_MAX_RUBY_LENGTH,
+};
+
+use crate::converter::{ConverterOptions, LineItemConverter};
+
+/// Converts items into Narou notations.
+///
+/// Sesame becomes dot ruby on each character since Narou has no emphasis notation,
+/// and comments are dropped. Ruby over the limits is parenthesized with a warning.
+#[derive(Default)]
+pub struct NarouLineItemConverter {
+    options: ConverterOptions,
+}
+
+impl NarouLineItemConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self { options }
+    }
+
+    pub(crate) fn is_within_limits(text: &str, ruby: &str) -> bool {
+        text.chars().count() <= NAROU_MAX_RUBY_LENGTH
+            && ruby.chars().count() <= NAROU_MAX_RUBY_LENGTH
+    }
+
+    // Escapes brackets which would be taken as ruby, such as `《` and parentheses around kana.
+    fn escape(text: &str) -> String {
+        let mut escaped = String::with_capacity(text.len());
+        for (index, c) in text.char_indices() {
+            let is_ruby = match c {
+                '《' => true,
+                '(' | '（' => {
+                    let rest = &text[index + c.len_utf8()..];
+                    match rest.find([')', '）']) {
+                        Some(end) => {
+                            end > 0
+                                && rest[..end]
+                                    .chars()
+                                    .all(|c| matches!(c, 'ぁ'..='ゖ' | 'ァ'..='ヺ' | 'ー'))
+                        }
+                        None => false,
+                    }
+                }
+                _ => false,
+            };
+            if is_ruby {
+                escaped.push('|');
+            }
+            escaped.push(c);
+        }
+        escaped
+    }
+}
+
+impl LineItemConverter for NarouLineItemConverter {
+    fn options(&self) -> &ConverterOptions {
+        &self.options
+    }
+
+    fn convert(&self, item: LineItem) -> String {
+        match item {
+            LineItem::Text(text) => Self::escape(&text),
+            LineItem::Comma(comma) => comma,
+            LineItem::Comment(_) => "".to_string(),
+            LineItem::TextWithRuby((text, ruby)) if !Self::is_within_limits(&text, &ruby) => {
+                warn!(
+                    "ruby longer than {} characters is parenthesized: {}《{}》",
+                    NAROU_MAX_RUBY_LENGTH, text, ruby
+                );
+                format!("{}|（{}）", Self::escape(&text), ruby)
+            }
+            LineItem::TextWithRuby((text, ruby)) => format!("|{text}《{ruby}》"),
+            LineItem::EndOfSentence(Terminator::Normal(terminator)) => terminator,
+            LineItem::EndOfSentence(Terminator::Exclamation(terminator)) => {
+                format!("{}　", terminator)
+            }
+            LineItem::EndOfSection(_) => "".to_string(),
+            LineItem::TextWithSesame((text, _)) => {
+                text.chars().map(|c| format!("|{c}《・》")).collect()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_text() {
+        let item = LineItem::Text("無い《仮》(かな)(仮)".to_string());
+        let result = NarouLineItemConverter::default().convert(item);
+        assert_eq!(result, "無い|《仮》|(かな)(仮)");
+    }
+
+    #[test]
+    fn test_convert_rich_text() {
+        let item = LineItem::TextWithRuby(("吾輩".to_string(), "わがはい".to_string()));
+        let result = NarouLineItemConverter::default().convert(item);
+        assert_eq!(result, "|吾輩《わがはい》");
+    }
+
+    #[test]
+    fn test_convert_long_ruby() {
+        let item =
+            LineItem::TextWithRuby(("寿限無".to_string(), "じゅげむじゅげむごこうの".to_string()));
+        let result = NarouLineItemConverter::default().convert(item);
+        assert_eq!(result, "寿限無|（じゅげむじゅげむごこうの）");
+    }
+
+    #[test]
+    fn test_convert_text_with_sesame() {
+        let item = LineItem::TextWithSesame(("まだ".to_string(), '・'));
+        let result = NarouLineItemConverter::default().convert(item);
+        assert_eq!(result, "|ま《・》|だ《・》");
+    }
+}
@@ -0,0 +1,70 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{ConverterOptions, ManuscriptConverter};
+
+use super::section_converter::NarouSectionConverter;
+
+/// Converts a manuscript into the body of a Shosetsuka ni Naro episode.
+///
+/// Titles are left out since they are entered separately from the body,
+/// and scenes are separated by the scene separator.
+#[derive(Default)]
+pub struct NarouManuscriptConverter {
+    item_converter: NarouSectionConverter,
+}
+
+impl NarouManuscriptConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: NarouSectionConverter::new(options),
+        }
+    }
+}
+
+impl ManuscriptConverter for NarouManuscriptConverter {
+    type ItemConverter = NarouSectionConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(
+        &self,
+        sections: impl IntoIterator<Item = Section>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        self.write_scenes(sections, writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::{ManuscriptParser, Platform, WebNovelParser};
+
+    use crate::converter::rstrial::manuscript_converter::RstrialManuscriptConverter;
+
+    use super::*;
+
+    #[test]
+    fn test_convert() {
+        let input = "```\n{吾輩|わがはい}は{三毛猫|みけねこ}である。\n```\n```\n「{見当|けんとう}がつかぬ」\n```\n";
+        let result =
+            NarouManuscriptConverter::default().convert(ManuscriptParser::new(input).collect());
+        assert_eq!(
+            result,
+            "　吾輩《わがはい》は三毛猫《みけねこ》である。\n\n†\n\n「見当《けんとう》がつかぬ」\n"
+        );
+    }
+
+    #[test]
+    fn test_convert_and_import() {
+        let input = "```\n{吾輩|わがはい}は猫である。名前は{まだ|.}無い(かな)！\n「{見当|けんとう}がつかぬ」\n```\n";
+        let narou =
+            NarouManuscriptConverter::default().convert(ManuscriptParser::new(input).collect());
+        let result = RstrialManuscriptConverter::default()
+            .convert(WebNovelParser::new(&narou, Platform::Narou).collect());
+        assert_eq!(result, input);
+    }
+}
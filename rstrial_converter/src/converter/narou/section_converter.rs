@@ -0,0 +1,24 @@
+use crate::converter::{ConverterOptions, SectionConverter};
+
+use super::line_converter::NarouLineConverter;
+
+#[derive(Default)]
+pub struct NarouSectionConverter {
+    item_converter: NarouLineConverter,
+}
+
+impl NarouSectionConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: NarouLineConverter::new(options),
+        }
+    }
+}
+
+impl SectionConverter for NarouSectionConverter {
+    type ItemConverter = NarouLineConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+}
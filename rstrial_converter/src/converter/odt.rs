@@ -0,0 +1,5 @@
+pub mod line_converter;
+pub mod line_item_converter;
+pub mod manuscript_converter;
+pub(crate) mod package;
+pub mod section_converter;
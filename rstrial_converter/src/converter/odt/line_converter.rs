@@ -0,0 +1,108 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::{Line, LineItem};
+
+use crate::converter::{CommentPolicy, ConverterOptions, LineConverter};
+
+use super::line_item_converter::OdtLineItemConverter;
+
+#[derive(Default)]
+pub struct OdtLineConverter {
+    item_converter: OdtLineItemConverter,
+}
+
+impl OdtLineConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: OdtLineItemConverter::new(options),
+        }
+    }
+
+    fn write_paragraph(
+        &self,
+        style: &str,
+        prefix: &str,
+        items: Vec<LineItem>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        write!(
+            writer,
+            "<text:p text:style-name=\"{}\">{}",
+            style,
+            OdtLineItemConverter::escape(prefix)
+        )?;
+        self.write_items(items, writer)?;
+        write!(writer, "</text:p>")
+    }
+}
+
+impl LineConverter for OdtLineConverter {
+    type ItemConverter = OdtLineItemConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(&self, line: Line, writer: &mut dyn Write) -> io::Result<()> {
+        match line {
+            Line::Paragraph(items) if items.is_empty() => {
+                write!(writer, "<text:p text:style-name=\"Text_20_body\"/>")?
+            }
+            Line::Paragraph(items) => {
+                self.write_paragraph("Text_20_body", &self.options().indent, items, writer)?
+            }
+            Line::Conversation(items) => self.write_paragraph("Conversation", "", items, writer)?,
+            Line::Quotation(items) => self.write_paragraph("Quotations", "", items, writer)?,
+            // Comments are kept as hidden paragraphs.
+            Line::Comment(comment) | Line::BlockComment(comment) => match self.options().comments {
+                CommentPolicy::Keep => {
+                    for line in comment.lines() {
+                        write!(
+                            writer,
+                            "<text:p text:style-name=\"Comment\">{}</text:p>{}",
+                            OdtLineItemConverter::escape(line),
+                            self.line_separator()
+                        )?;
+                    }
+                    return Ok(());
+                }
+                CommentPolicy::Drop => return Ok(()),
+            },
+            Line::AuthorOnly(_) => return Ok(()),
+        }
+        write!(writer, "{}", self.line_separator())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::tokens::line_item::Terminator;
+
+    use super::*;
+
+    #[test]
+    fn test_convert_paragraph() {
+        let line = Line::Paragraph(vec![
+            LineItem::Text("吾輩は猫である".to_string()),
+            LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+        ]);
+        let result = OdtLineConverter::default().convert(line);
+        assert_eq!(
+            result,
+            "<text:p text:style-name=\"Text_20_body\">　吾輩は猫である。</text:p>\n"
+        );
+    }
+
+    #[test]
+    fn test_convert_conversation() {
+        let line = Line::Conversation(vec![
+            LineItem::Text("「にゃあ".to_string()),
+            LineItem::EndOfSentence(Terminator::Normal("」".to_string())),
+        ]);
+        let result = OdtLineConverter::default().convert(line);
+        assert_eq!(
+            result,
+            "<text:p text:style-name=\"Conversation\">「にゃあ」</text:p>\n"
+        );
+    }
+}
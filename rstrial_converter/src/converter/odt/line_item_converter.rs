@@ -0,0 +1,124 @@
+use rstrial_parser::tokens::{line_item::Terminator, LineItem};
+
+use crate::converter::{xml, CommentPolicy, ConverterOptions, LineItemConverter};
+
+/// Converts items into the paragraph contents of OpenDocument Text.
+#[derive(Default)]
+pub struct OdtLineItemConverter {
+    options: ConverterOptions,
+}
+
+impl OdtLineItemConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self { options }
+    }
+
+    /// Escapes text, keeping consecutive and leading spaces which ODF would collapse otherwise.
+    pub fn escape(text: &str) -> String {
+        let mut escaped = String::with_capacity(text.len());
+        let mut spaces = 0;
+        let flush = |escaped: &mut String, spaces: &mut usize| {
+            match *spaces {
+                0 => {}
+                1 if !escaped.is_empty() => escaped.push(' '),
+                1 => escaped.push_str("<text:s/>"),
+                n if !escaped.is_empty() => {
+                    escaped.push_str(&format!(" <text:s text:c=\"{}\"/>", n - 1))
+                }
+                n => escaped.push_str(&format!("<text:s text:c=\"{}\"/>", n)),
+            }
+            *spaces = 0;
+        };
+        for c in text.chars() {
+            match c {
+                ' ' => spaces += 1,
+                '\t' => {
+                    flush(&mut escaped, &mut spaces);
+                    escaped.push_str("<text:tab/>");
+                }
+                c => {
+                    flush(&mut escaped, &mut spaces);
+                    escaped.push_str(&xml::escape(&c.to_string()));
+                }
+            }
+        }
+        flush(&mut escaped, &mut spaces);
+        escaped
+    }
+
+    fn span(style: &str, text: &str) -> String {
+        format!(
+            "<text:span text:style-name=\"{}\">{}</text:span>",
+            style,
+            Self::escape(text)
+        )
+    }
+}
+
+impl LineItemConverter for OdtLineItemConverter {
+    fn options(&self) -> &ConverterOptions {
+        &self.options
+    }
+
+    fn convert(&self, item: LineItem) -> String {
+        match item {
+            LineItem::Text(text) => Self::escape(&text),
+            LineItem::Comma(comma) => Self::escape(&comma),
+            LineItem::Comment(comment) => match self.options.comments {
+                CommentPolicy::Keep => Self::span("Hidden", &comment),
+                CommentPolicy::Drop => "".to_string(),
+            },
+            LineItem::TextWithRuby((text, ruby)) => format!(
+                "<text:ruby text:style-name=\"Ruby\"><text:ruby-base>{}</text:ruby-base><text:ruby-text>{}</text:ruby-text></text:ruby>",
+                Self::escape(&text),
+                Self::escape(&ruby)
+            ),
+            LineItem::EndOfSentence(Terminator::Normal(terminator)) => Self::escape(&terminator),
+            LineItem::EndOfSentence(Terminator::Exclamation(terminator)) => {
+                format!("{}　", Self::escape(&terminator))
+            }
+            LineItem::EndOfSection(_) => "".to_string(),
+            LineItem::TextWithSesame((text, mark)) => match mark {
+                '﹅' | '﹆' => Self::span("Sesame", &text),
+                _ => Self::span("Dot", &text),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            OdtLineItemConverter::escape("  a  b <c>"),
+            "<text:s text:c=\"2\"/>a <text:s text:c=\"1\"/>b &lt;c&gt;"
+        );
+        assert_eq!(
+            OdtLineItemConverter::escape(" a\tb"),
+            "<text:s/>a<text:tab/>b"
+        );
+    }
+
+    #[test]
+    fn test_convert_rich_text() {
+        let item = LineItem::TextWithRuby(("吾輩".to_string(), "わがはい".to_string()));
+        let result = OdtLineItemConverter::default().convert(item);
+        assert_eq!(
+            result,
+            "<text:ruby text:style-name=\"Ruby\"><text:ruby-base>吾輩</text:ruby-base><text:ruby-text>わがはい</text:ruby-text></text:ruby>"
+        );
+    }
+
+    #[test]
+    fn test_convert_text_with_sesame() {
+        let item = LineItem::TextWithSesame(("まだ".to_string(), '・'));
+        let result = OdtLineItemConverter::default().convert(item);
+        assert_eq!(
+            result,
+            "<text:span text:style-name=\"Dot\">まだ</text:span>"
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use std::io::{self, Write};
+
+use log::info;
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{is_published, ConverterOptions, ManuscriptConverter, SectionConverter};
+
+use super::{
+    line_item_converter::OdtLineItemConverter, package::Package,
+    section_converter::OdtSectionConverter,
+};
+
+/// Converts a manuscript into an OpenDocument Text document.
+///
+/// Vertical documents use landscape A4 pages with the page grid of the options.
+/// Each `# ` title starts a new page, and the first one becomes the title of the document.
+/// The output is a zip archive, so use `write` instead of `convert`.
+#[derive(Default)]
+pub struct OdtManuscriptConverter {
+    item_converter: OdtSectionConverter,
+}
+
+impl OdtManuscriptConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: OdtSectionConverter::new(options),
+        }
+    }
+}
+
+impl ManuscriptConverter for OdtManuscriptConverter {
+    type ItemConverter = OdtSectionConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(
+        &self,
+        sections: impl IntoIterator<Item = Section>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        let mut package = Package {
+            writing_mode: self.options().writing_mode,
+            page_grid: self.options().page_grid,
+            ..Default::default()
+        };
+        let mut previous_was_scene = false;
+        for section in sections.into_iter().filter(is_published) {
+            info!("convert: {:?}", section);
+            let is_scene = matches!(section, Section::Scene(_, _));
+            match (&section, previous_was_scene) {
+                (Section::Title(title), _) => {
+                    package.title.get_or_insert_with(|| title.clone());
+                }
+                (Section::Scene(_, _), true) => writeln!(
+                    package.body,
+                    "<text:p text:style-name=\"Scene_20_Separator\">{}</text:p>",
+                    OdtLineItemConverter::escape(&self.options().scene_separator)
+                )?,
+                _ => {}
+            }
+            self.item_converter().write(section, &mut package.body)?;
+            previous_was_scene = is_scene;
+        }
+        package.write(writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quick_xml::{events::Event, Reader};
+    use zip::CompressionMethod;
+
+    use crate::converter::{
+        test_archive::{convert, read, INPUT},
+        WritingMode,
+    };
+
+    use super::*;
+
+    // Lists the children of `<office:text>` as `name[style]` and fails if the XML is not well-formed.
+    fn text_structure(xml: &str) -> Vec<String> {
+        let mut reader = Reader::from_str(xml);
+        let mut depth = None;
+        let mut children = vec![];
+        loop {
+            let event = reader.read_event();
+            match &event {
+                Ok(Event::Start(tag)) | Ok(Event::Empty(tag)) => {
+                    if tag.name().as_ref() == b"office:text" {
+                        depth = Some(0);
+                    } else if depth == Some(0) {
+                        let style = tag
+                            .try_get_attribute("text:style-name")
+                            .unwrap()
+                            .map(|value| value.unescape_value().unwrap().to_string())
+                            .unwrap_or_default();
+                        children.push(format!(
+                            "{}[{}]",
+                            String::from_utf8_lossy(tag.name().as_ref()),
+                            style
+                        ));
+                    }
+                }
+                Ok(Event::Eof) => break,
+                Ok(_) => {}
+                Err(error) => panic!("malformed XML: {:?}\n{}", error, xml),
+            }
+            match event {
+                Ok(Event::Start(tag)) if tag.name().as_ref() != b"office:text" => {
+                    depth = depth.map(|depth| depth + 1)
+                }
+                Ok(Event::End(tag)) if tag.name().as_ref() != b"office:text" => {
+                    depth = depth.map(|depth| depth - 1)
+                }
+                Ok(Event::End(_)) => depth = None,
+                _ => {}
+            }
+        }
+        children
+    }
+
+    #[test]
+    fn test_package_structure() {
+        let mut archive = convert(OdtManuscriptConverter::default(), INPUT);
+        let mimetype = archive.by_index(0).unwrap();
+        assert_eq!(mimetype.name(), "mimetype");
+        assert_eq!(mimetype.compression(), CompressionMethod::Stored);
+        drop(mimetype);
+        assert_eq!(
+            read(&mut archive, "mimetype"),
+            "application/vnd.oasis.opendocument.text"
+        );
+        for name in ["META-INF/manifest.xml", "meta.xml", "styles.xml"] {
+            text_structure(&read(&mut archive, name));
+        }
+        assert!(read(&mut archive, "meta.xml").contains("<dc:title>吾輩は猫である</dc:title>"));
+        let styles = read(&mut archive, "styles.xml");
+        assert!(styles.contains("style:writing-mode=\"tb-rl\""));
+        assert!(styles.contains("fo:break-before=\"page\""));
+    }
+
+    #[test]
+    fn test_content() {
+        let mut archive = convert(OdtManuscriptConverter::default(), INPUT);
+        let content = read(&mut archive, "content.xml");
+        assert_eq!(
+            text_structure(&content),
+            vec![
+                "text:h[Heading_20_1]",
+                "text:p[Text_20_body]",
+                "text:p[Scene_20_Separator]",
+                "text:p[Conversation]",
+                "text:h[Heading_20_1]",
+                "text:p[Text_20_body]",
+            ]
+        );
+        assert!(content.contains("<text:ruby text:style-name=\"Ruby\"><text:ruby-base>吾輩</text:ruby-base><text:ruby-text>わがはい</text:ruby-text></text:ruby>"));
+        assert!(content.contains("<text:span text:style-name=\"Dot\">まだ</text:span>"));
+    }
+
+    #[test]
+    fn test_horizontal_document() {
+        let converter = OdtManuscriptConverter::new(ConverterOptions {
+            writing_mode: WritingMode::Horizontal,
+            ..Default::default()
+        });
+        let mut archive = convert(converter, INPUT);
+        assert!(read(&mut archive, "styles.xml").contains("style:writing-mode=\"lr-tb\""));
+    }
+}
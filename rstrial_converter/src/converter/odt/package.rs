@@ -0,0 +1,185 @@
+use std::io::{self, Cursor, Write};
+
+use zip::{write::FileOptions, CompressionMethod, ZipWriter};
+
+use crate::converter::{xml, PageGrid, WritingMode};
+
+const MIMETYPE: &str = "application/vnd.oasis.opendocument.text";
+
+/// Width and height of A4 paper in millimetres.
+const A4: (f64, f64) = (210.0, 297.0);
+
+/// Page margin in millimetres.
+const MARGIN: f64 = 20.0;
+
+const NAMESPACES: &str = r#"xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" xmlns:style="urn:oasis:names:tc:opendocument:xmlns:style:1.0" xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0" xmlns:fo="urn:oasis:names:tc:opendocument:xmlns:xsl-fo-compatible:1.0" xmlns:svg="urn:oasis:names:tc:opendocument:xmlns:svg-compatible:1.0" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:meta="urn:oasis:names:tc:opendocument:xmlns:meta:1.0" office:version="1.3""#;
+
+const MANIFEST: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<manifest:manifest xmlns:manifest="urn:oasis:names:tc:opendocument:xmlns:manifest:1.0" manifest:version="1.3">
+<manifest:file-entry manifest:full-path="/" manifest:version="1.3" manifest:media-type="application/vnd.oasis.opendocument.text"/>
+<manifest:file-entry manifest:full-path="content.xml" manifest:media-type="text/xml"/>
+<manifest:file-entry manifest:full-path="styles.xml" manifest:media-type="text/xml"/>
+<manifest:file-entry manifest:full-path="meta.xml" manifest:media-type="text/xml"/>
+</manifest:manifest>
+"#;
+
+const STYLES: &str = r#"<office:font-face-decls>
+<style:font-face style:name="Mincho" svg:font-family="'游明朝', 'Noto Serif CJK JP', serif" style:font-family-generic="roman"/>
+<style:font-face style:name="Gothic" svg:font-family="'游ゴシック', 'Noto Sans CJK JP', sans-serif" style:font-family-generic="swiss"/>
+</office:font-face-decls>
+<office:styles>
+<style:default-style style:family="paragraph">
+<style:paragraph-properties fo:text-align="justify"/>
+<style:text-properties style:font-name-asian="Mincho" fo:font-size="10.5pt" style:font-size-asian="10.5pt" fo:language="en" fo:country="US" style:language-asian="ja" style:country-asian="JP"/>
+</style:default-style>
+<style:style style:name="Standard" style:family="paragraph" style:class="text"/>
+<style:style style:name="Text_20_body" style:display-name="Text body" style:family="paragraph" style:parent-style-name="Standard" style:class="text"/>
+<style:style style:name="Conversation" style:family="paragraph" style:parent-style-name="Text_20_body" style:class="text"/>
+<style:style style:name="Quotations" style:family="paragraph" style:parent-style-name="Text_20_body" style:class="html">
+<style:paragraph-properties fo:margin-left="2em"/>
+</style:style>
+<style:style style:name="Scene_20_Separator" style:display-name="Scene Separator" style:family="paragraph" style:parent-style-name="Text_20_body" style:class="text">
+<style:paragraph-properties fo:text-align="center"/>
+</style:style>
+<style:style style:name="Comment" style:family="paragraph" style:parent-style-name="Text_20_body" style:class="text">
+<style:text-properties text:display="none"/>
+</style:style>
+<style:style style:name="Heading_20_1" style:display-name="Heading 1" style:family="paragraph" style:parent-style-name="Standard" style:next-style-name="Text_20_body" style:default-outline-level="1" style:class="text">
+<style:paragraph-properties fo:break-before="page" fo:margin-bottom="1em" fo:keep-with-next="always"/>
+<style:text-properties style:font-name-asian="Gothic" fo:font-size="16pt" style:font-size-asian="16pt" fo:font-weight="bold" style:font-weight-asian="bold"/>
+</style:style>
+<style:style style:name="Dot" style:family="text">
+<style:text-properties style:text-emphasize="dot above"/>
+</style:style>
+<style:style style:name="Sesame" style:family="text">
+<style:text-properties style:text-emphasize="accent above"/>
+</style:style>
+<style:style style:name="Hidden" style:family="text">
+<style:text-properties text:display="none"/>
+</style:style>
+</office:styles>
+"#;
+
+/// Contents of an OpenDocument Text package. `body` holds the paragraphs of `<office:text>`.
+#[derive(Default)]
+pub(crate) struct Package {
+    pub title: Option<String>,
+    pub body: Vec<u8>,
+    pub writing_mode: WritingMode,
+    pub page_grid: PageGrid,
+}
+
+impl Package {
+    /// Writes the package as a zip archive.
+    pub fn write(&self, writer: &mut dyn Write) -> io::Result<()> {
+        let stored = FileOptions::default().compression_method(CompressionMethod::Stored);
+        let deflated = FileOptions::default().compression_method(CompressionMethod::Deflated);
+        let mut zip = ZipWriter::new(Cursor::new(vec![]));
+        // `mimetype` must be the first entry and must not be compressed.
+        zip.start_file("mimetype", stored)?;
+        zip.write_all(MIMETYPE.as_bytes())?;
+        zip.start_file("META-INF/manifest.xml", deflated)?;
+        zip.write_all(MANIFEST.as_bytes())?;
+        zip.start_file("meta.xml", deflated)?;
+        zip.write_all(self.meta().as_bytes())?;
+        zip.start_file("styles.xml", deflated)?;
+        zip.write_all(self.styles().as_bytes())?;
+        zip.start_file("content.xml", deflated)?;
+        write!(
+            zip,
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<office:document-content {}>
+<office:automatic-styles>
+<style:style style:name="Ruby" style:family="ruby">
+<style:ruby-properties style:ruby-position="above" style:ruby-align="center"/>
+</style:style>
+</office:automatic-styles>
+<office:body>
+<office:text>
+"#,
+            NAMESPACES
+        )?;
+        zip.write_all(&self.body)?;
+        zip.write_all(b"</office:text>\n</office:body>\n</office:document-content>\n")?;
+        writer.write_all(&zip.finish()?.into_inner())
+    }
+
+    fn meta(&self) -> String {
+        let title = match &self.title {
+            Some(title) => format!("<dc:title>{}</dc:title>\n", xml::escape(title)),
+            None => "".to_string(),
+        };
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<office:document-meta {}>
+<office:meta>
+{}<dc:language>ja-JP</dc:language>
+</office:meta>
+</office:document-meta>
+"#,
+            NAMESPACES, title
+        )
+    }
+
+    fn styles(&self) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<office:document-styles {}>
+{}<office:automatic-styles>
+{}</office:automatic-styles>
+<office:master-styles>
+<style:master-page style:name="Standard" style:page-layout-name="Manuscript"/>
+</office:master-styles>
+</office:document-styles>
+"#,
+            NAMESPACES,
+            STYLES,
+            self.page_layout()
+        )
+    }
+
+    // Vertical documents are laid out on landscape pages like Japanese manuscript paper.
+    fn page_layout(&self) -> String {
+        let (width, height, orientation, writing_mode) = match self.writing_mode {
+            WritingMode::Vertical => (A4.1, A4.0, "landscape", "tb-rl"),
+            WritingMode::Horizontal => (A4.0, A4.1, "portrait", "lr-tb"),
+        };
+        let (line_length, page_depth) = match self.writing_mode {
+            WritingMode::Vertical => (height - MARGIN * 2.0, width - MARGIN * 2.0),
+            WritingMode::Horizontal => (width - MARGIN * 2.0, height - MARGIN * 2.0),
+        };
+        let char_pitch = line_length / f64::from(self.page_grid.chars);
+        let line_pitch = page_depth / f64::from(self.page_grid.lines);
+        format!(
+            r#"<style:page-layout style:name="Manuscript">
+<style:page-layout-properties fo:page-width="{width}mm" fo:page-height="{height}mm" style:print-orientation="{orientation}" fo:margin-top="{MARGIN}mm" fo:margin-bottom="{MARGIN}mm" fo:margin-left="{MARGIN}mm" fo:margin-right="{MARGIN}mm" style:writing-mode="{writing_mode}" style:layout-grid-mode="both" style:layout-grid-lines="{lines}" style:layout-grid-base-height="{line_pitch:.2}mm" style:layout-grid-base-width="{char_pitch:.2}mm" style:layout-grid-ruby-height="0mm" style:layout-grid-print="false" style:layout-grid-display="false" style:layout-grid-snap-to="true"/>
+</style:page-layout>
+"#,
+            lines = self.page_grid.lines
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_layout() {
+        let package = Package::default();
+        let layout = package.page_layout();
+        assert!(layout.contains("fo:page-width=\"297mm\" fo:page-height=\"210mm\""));
+        assert!(layout.contains("style:writing-mode=\"tb-rl\""));
+        // 170mm / 40 characters and 257mm / 30 lines.
+        assert!(layout.contains("style:layout-grid-base-height=\"8.57mm\""));
+        assert!(layout.contains("style:layout-grid-base-width=\"4.25mm\""));
+
+        let package = Package {
+            writing_mode: WritingMode::Horizontal,
+            ..Default::default()
+        };
+        let layout = package.page_layout();
+        assert!(layout.contains("fo:page-width=\"210mm\" fo:page-height=\"297mm\""));
+        assert!(layout.contains("style:writing-mode=\"lr-tb\""));
+    }
+}
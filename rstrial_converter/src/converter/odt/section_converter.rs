@@ -0,0 +1,42 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{ConverterOptions, LineConverter, SectionConverter};
+
+use super::{line_converter::OdtLineConverter, line_item_converter::OdtLineItemConverter};
+
+#[derive(Default)]
+pub struct OdtSectionConverter {
+    item_converter: OdtLineConverter,
+}
+
+impl OdtSectionConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: OdtLineConverter::new(options),
+        }
+    }
+}
+
+impl SectionConverter for OdtSectionConverter {
+    type ItemConverter = OdtLineConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(&self, section: Section, writer: &mut dyn Write) -> io::Result<()> {
+        match section {
+            // The heading style starts a new page, so that each chapter begins on its own page.
+            Section::Title(title) => writeln!(
+                writer,
+                "<text:h text:style-name=\"Heading_20_1\" text:outline-level=\"1\">{}</text:h>",
+                OdtLineItemConverter::escape(&title)
+            ),
+            Section::Scene(_, body) => body
+                .into_iter()
+                .try_for_each(|line| self.item_converter().write(line, writer)),
+        }
+    }
+}
@@ -0,0 +1,306 @@
+use std::{fmt::Display, str::FromStr};
+
+use rstrial_parser::tokens::LineItem;
+
+/// How converters render `TextWithRuby`.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum RubyPolicy {
+    /// Render ruby in the notation of the output format.
+    #[default]
+    Keep,
+    /// Render the base text only.
+    Strip,
+    /// Render the ruby in parentheses after the base text such as `漢字（かんじ）`.
+    Parenthesize,
+    /// Render the ruby only, which suits reading aloud.
+    Reading,
+}
+
+/// How converters render `TextWithSesame`.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum SesamePolicy {
+    /// Render emphasis in the notation of the output format.
+    #[default]
+    Keep,
+    /// Render the text only.
+    Strip,
+    /// Render the text in angle brackets such as `〈まだ〉`.
+    Bracket,
+}
+
+/// How converters render comments.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum CommentPolicy {
+    /// Drop comments from the output.
+    #[default]
+    Drop,
+    /// Render comments in the comment notation of the output format.
+    Keep,
+}
+
+/// Text direction of formats that support both.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum WritingMode {
+    /// Top to bottom, lines from right to left.
+    #[default]
+    Vertical,
+    /// Left to right, lines from top to bottom.
+    Horizontal,
+}
+
+/// Characters per line and lines per page of page-oriented formats such as `40x30`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct PageGrid {
+    pub chars: u32,
+    pub lines: u32,
+}
+
+impl Default for PageGrid {
+    fn default() -> Self {
+        Self {
+            chars: 40,
+            lines: 30,
+        }
+    }
+}
+
+/// Options shared by all layers of a converter.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ConverterOptions {
+    /// A line put between scenes.
+    pub scene_separator: String,
+    pub comments: CommentPolicy,
+    pub ruby: RubyPolicy,
+    pub sesame: SesamePolicy,
+    /// Characters put at the beginning of paragraphs.
+    pub indent: String,
+    pub writing_mode: WritingMode,
+    pub page_grid: PageGrid,
+    /// Voice name of dialogue lines in speech formats.
+    pub dialogue_voice: Option<String>,
+}
+
+impl Default for ConverterOptions {
+    fn default() -> Self {
+        Self {
+            scene_separator: "†".to_string(),
+            comments: CommentPolicy::default(),
+            ruby: RubyPolicy::default(),
+            sesame: SesamePolicy::default(),
+            indent: "　".to_string(),
+            writing_mode: WritingMode::default(),
+            page_grid: PageGrid::default(),
+            dialogue_voice: None,
+        }
+    }
+}
+
+impl ConverterOptions {
+    /// Rewrites ruby into plain text unless the ruby policy is `Keep`.
+    pub fn apply_ruby_policy(&self, item: LineItem) -> LineItem {
+        match (item, self.ruby) {
+            (LineItem::TextWithRuby((text, _)), RubyPolicy::Strip) => LineItem::Text(text),
+            (LineItem::TextWithRuby((text, ruby)), RubyPolicy::Parenthesize) => {
+                LineItem::Text(format!("{text}（{ruby}）"))
+            }
+            (LineItem::TextWithRuby((_, ruby)), RubyPolicy::Reading) => LineItem::Text(ruby),
+            (item, _) => item,
+        }
+    }
+
+    /// Rewrites sesame into plain text unless the sesame policy is `Keep`.
+    pub fn apply_sesame_policy(&self, item: LineItem) -> LineItem {
+        match (item, self.sesame) {
+            (LineItem::TextWithSesame((text, _)), SesamePolicy::Strip) => LineItem::Text(text),
+            (LineItem::TextWithSesame((text, _)), SesamePolicy::Bracket) => {
+                LineItem::Text(format!("〈{text}〉"))
+            }
+            (item, _) => item,
+        }
+    }
+
+    /// Applies both the ruby and the sesame policies.
+    pub fn apply_policies(&self, item: LineItem) -> LineItem {
+        self.apply_sesame_policy(self.apply_ruby_policy(item))
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParsePolicyError(String);
+
+impl Display for ParsePolicyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown policy: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParsePolicyError {}
+
+impl FromStr for RubyPolicy {
+    type Err = ParsePolicyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "keep" => Ok(RubyPolicy::Keep),
+            "strip" => Ok(RubyPolicy::Strip),
+            "parenthesize" => Ok(RubyPolicy::Parenthesize),
+            "reading" => Ok(RubyPolicy::Reading),
+            _ => Err(ParsePolicyError(s.to_string())),
+        }
+    }
+}
+
+impl FromStr for CommentPolicy {
+    type Err = ParsePolicyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "drop" => Ok(CommentPolicy::Drop),
+            "keep" => Ok(CommentPolicy::Keep),
+            _ => Err(ParsePolicyError(s.to_string())),
+        }
+    }
+}
+
+impl FromStr for SesamePolicy {
+    type Err = ParsePolicyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "keep" => Ok(SesamePolicy::Keep),
+            "strip" => Ok(SesamePolicy::Strip),
+            "bracket" => Ok(SesamePolicy::Bracket),
+            _ => Err(ParsePolicyError(s.to_string())),
+        }
+    }
+}
+
+impl FromStr for WritingMode {
+    type Err = ParsePolicyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "vertical" => Ok(WritingMode::Vertical),
+            "horizontal" => Ok(WritingMode::Horizontal),
+            _ => Err(ParsePolicyError(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParsePageGridError(String);
+
+impl Display for ParsePageGridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid page grid: {} (expected such as 40x30)", self.0)
+    }
+}
+
+impl std::error::Error for ParsePageGridError {}
+
+impl FromStr for PageGrid {
+    type Err = ParsePageGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParsePageGridError(s.to_string());
+        let (chars, lines) = s.split_once(['x', '×']).ok_or_else(error)?;
+        let chars = chars.trim().parse::<u32>().map_err(|_| error())?;
+        let lines = lines.trim().parse::<u32>().map_err(|_| error())?;
+        if chars == 0 || lines == 0 {
+            return Err(error());
+        }
+        Ok(PageGrid { chars, lines })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_ruby_policy() {
+        let item = LineItem::TextWithRuby(("漢字".to_string(), "かんじ".to_string()));
+        let options = ConverterOptions {
+            ruby: RubyPolicy::Parenthesize,
+            ..Default::default()
+        };
+        assert_eq!(
+            options.apply_ruby_policy(item.clone()),
+            LineItem::Text("漢字（かんじ）".to_string())
+        );
+        let options = ConverterOptions {
+            ruby: RubyPolicy::Strip,
+            ..Default::default()
+        };
+        assert_eq!(
+            options.apply_ruby_policy(item.clone()),
+            LineItem::Text("漢字".to_string())
+        );
+        let options = ConverterOptions {
+            ruby: RubyPolicy::Reading,
+            ..Default::default()
+        };
+        assert_eq!(
+            options.apply_ruby_policy(item.clone()),
+            LineItem::Text("かんじ".to_string())
+        );
+        assert_eq!(
+            ConverterOptions::default().apply_ruby_policy(item.clone()),
+            item
+        );
+    }
+
+    #[test]
+    fn test_apply_sesame_policy() {
+        let item = LineItem::TextWithSesame(("まだ".to_string(), '・'));
+        let options = ConverterOptions {
+            sesame: SesamePolicy::Bracket,
+            ..Default::default()
+        };
+        assert_eq!(
+            options.apply_sesame_policy(item.clone()),
+            LineItem::Text("〈まだ〉".to_string())
+        );
+        let options = ConverterOptions {
+            sesame: SesamePolicy::Strip,
+            ..Default::default()
+        };
+        assert_eq!(
+            options.apply_sesame_policy(item.clone()),
+            LineItem::Text("まだ".to_string())
+        );
+        assert_eq!(
+            ConverterOptions::default().apply_sesame_policy(item.clone()),
+            item
+        );
+    }
+
+    #[test]
+    fn test_parse_policy() {
+        assert_eq!("parenthesize".parse(), Ok(RubyPolicy::Parenthesize));
+        assert_eq!("keep".parse(), Ok(CommentPolicy::Keep));
+        assert_eq!("bracket".parse(), Ok(SesamePolicy::Bracket));
+        assert_eq!("horizontal".parse(), Ok(WritingMode::Horizontal));
+        assert!("unknown".parse::<RubyPolicy>().is_err());
+    }
+
+    #[test]
+    fn test_parse_page_grid() {
+        assert_eq!(
+            "20x20".parse(),
+            Ok(PageGrid {
+                chars: 20,
+                lines: 20
+            })
+        );
+        assert_eq!(
+            "42×34".parse(),
+            Ok(PageGrid {
+                chars: 42,
+                lines: 34
+            })
+        );
+        assert!("40".parse::<PageGrid>().is_err());
+        assert!("0x30".parse::<PageGrid>().is_err());
+    }
+}
@@ -0,0 +1,65 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::{Line, LineItem};
+
+use crate::converter::{ConverterOptions, LineConverter};
+
+use super::line_item_converter::PixivLineItemConverter;
+
+#[derive(Default)]
+pub struct PixivLineConverter {
+    item_converter: PixivLineItemConverter,
+}
+
+impl PixivLineConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: PixivLineItemConverter::new(options),
+        }
+    }
+}
+
+impl LineConverter for PixivLineConverter {
+    type ItemConverter = PixivLineItemConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write_items(&self, items: Vec<LineItem>, writer: &mut dyn Write) -> io::Result<()> {
+        self.write_items_trimmed(items, writer)
+    }
+
+    fn write(&self, line: Line, writer: &mut dyn Write) -> io::Result<()> {
+        match line {
+            Line::Paragraph(items) => {
+                write!(writer, "{}", self.options().indent)?;
+                self.write_items(items, writer)?;
+            }
+            Line::Conversation(items) | Line::Quotation(items) => {
+                self.write_items(items, writer)?;
+            }
+            Line::Comment(_) | Line::BlockComment(_) | Line::AuthorOnly(_) => return Ok(()),
+        }
+        write!(writer, "{}", self.line_separator())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::tokens::line_item::Terminator;
+
+    use super::*;
+
+    #[test]
+    fn test_convert_conversation() {
+        let line = Line::Conversation(vec![
+            LineItem::Text("「にゃあ".to_string()),
+            LineItem::EndOfSentence(Terminator::Exclamation("！".to_string())),
+            LineItem::Text("にゃあ".to_string()),
+            LineItem::EndOfSentence(Terminator::Normal("」".to_string())),
+        ]);
+        let result = PixivLineConverter::default().convert(line);
+        assert_eq!(result, "「にゃあ！　にゃあ」\n");
+    }
+}
@@ -0,0 +1,67 @@
+use rstrial_parser::tokens::{line_item::Terminator, LineItem};
+
+use crate::converter::{ConverterOptions, LineItemConverter};
+
+/// Converts items into pixiv novel notations.
+///
+/// Sesame becomes dot ruby on each character since pixiv has no emphasis notation,
+/// and comments are dropped.
+#[derive(Default)]
+pub struct PixivLineItemConverter {
+    options: ConverterOptions,
+}
+
+impl PixivLineItemConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl LineItemConverter for PixivLineItemConverter {
+    fn options(&self) -> &ConverterOptions {
+        &self.options
+    }
+
+    fn convert(&self, item: LineItem) -> String {
+        match item {
+            LineItem::Text(text) => text,
+            LineItem::Comma(comma) => comma,
+            LineItem::Comment(_) => "".to_string(),
+            LineItem::TextWithRuby((text, ruby)) => format!("[[rb:{text} > {ruby}]]"),
+            LineItem::EndOfSentence(Terminator::Normal(terminator)) => terminator,
+            LineItem::EndOfSentence(Terminator::Exclamation(terminator)) => {
+                format!("{}　", terminator)
+            }
+            LineItem::EndOfSection(_) => "\n[newpage]\n".to_string(),
+            LineItem::TextWithSesame((text, _)) => {
+                text.chars().map(|c| format!("[[rb:{c} > ・]]")).collect()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_rich_text() {
+        let item = LineItem::TextWithRuby(("吾輩".to_string(), "わがはい".to_string()));
+        let result = PixivLineItemConverter::default().convert(item);
+        assert_eq!(result, "[[rb:吾輩 > わがはい]]");
+    }
+
+    #[test]
+    fn test_convert_text_with_sesame() {
+        let item = LineItem::TextWithSesame(("まだ".to_string(), '・'));
+        let result = PixivLineItemConverter::default().convert(item);
+        assert_eq!(result, "[[rb:ま > ・]][[rb:だ > ・]]");
+    }
+
+    #[test]
+    fn test_convert_comment() {
+        let item = LineItem::Comment("メモ".to_string());
+        let result = PixivLineItemConverter::default().convert(item);
+        assert_eq!(result, "");
+    }
+}
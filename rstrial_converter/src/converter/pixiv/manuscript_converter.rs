@@ -0,0 +1,72 @@
+use std::io::{self, Write};
+
+use log::info;
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{is_published, ConverterOptions, ManuscriptConverter, SectionConverter};
+
+use super::section_converter::PixivSectionConverter;
+
+/// Converts a manuscript into a pixiv novel.
+///
+/// Titles become `[chapter:]` and each scene or chapter starts a new page by `[newpage]`
+/// instead of the scene separator.
+/// `[jump:n]` is never written, because manuscripts have no syntax to refer to other scenes.
+#[derive(Default)]
+pub struct PixivManuscriptConverter {
+    item_converter: PixivSectionConverter,
+}
+
+impl PixivManuscriptConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: PixivSectionConverter::new(options),
+        }
+    }
+}
+
+impl ManuscriptConverter for PixivManuscriptConverter {
+    type ItemConverter = PixivSectionConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(
+        &self,
+        sections: impl IntoIterator<Item = Section>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        let mut previous_was_scene = false;
+        for (index, section) in sections.into_iter().filter(is_published).enumerate() {
+            info!("convert: {:?}", section);
+            let is_scene = matches!(section, Section::Scene(_, _));
+            match (&section, previous_was_scene) {
+                _ if index == 0 => {}
+                (Section::Scene(_, _), false) => writeln!(writer)?,
+                _ => write!(writer, "\n[newpage]\n")?,
+            }
+            self.item_converter().write(section, writer)?;
+            previous_was_scene = is_scene;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::ManuscriptParser;
+
+    use super::*;
+
+    #[test]
+    fn test_convert() {
+        let input = "# 一章\n\n```\n{吾輩|わがはい}は猫である。名前は{まだ|.}無い。\n// メモ\n```\n```\n「にゃあ」\n```\n# 二章\n\n```\nどこで生れたかとんと見当がつかぬ。\n```\n";
+        let result =
+            PixivManuscriptConverter::default().convert(ManuscriptParser::new(input).collect());
+        assert_eq!(
+            result,
+            "[chapter:一章]\n\n　[[rb:吾輩 > わがはい]]は猫である。名前は[[rb:ま > ・]][[rb:だ > ・]]無い。\n\n[newpage]\n「にゃあ」\n\n[newpage]\n[chapter:二章]\n\n　どこで生れたかとんと見当がつかぬ。\n"
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{ConverterOptions, LineConverter, SectionConverter};
+
+use super::line_converter::PixivLineConverter;
+
+#[derive(Default)]
+pub struct PixivSectionConverter {
+    item_converter: PixivLineConverter,
+}
+
+impl PixivSectionConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: PixivLineConverter::new(options),
+        }
+    }
+}
+
+impl SectionConverter for PixivSectionConverter {
+    type ItemConverter = PixivLineConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(&self, section: Section, writer: &mut dyn Write) -> io::Result<()> {
+        match section {
+            Section::Title(title) => writeln!(writer, "[chapter:{}]", title),
+            Section::Scene(_, body) => body
+                .into_iter()
+                .try_for_each(|line| self.item_converter().write(line, writer)),
+        }
+    }
+}
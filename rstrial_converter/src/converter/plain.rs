@@ -0,0 +1,4 @@
+pub mod line_converter;
+pub mod line_item_converter;
+pub mod manuscript_converter;
+pub mod section_converter;
@@ -0,0 +1,109 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::{Line, LineItem};
+
+use crate::converter::{CommentPolicy, ConverterOptions, LineConverter};
+
+use super::line_item_converter::PlainLineItemConverter;
+
+#[derive(Default)]
+pub struct PlainLineConverter {
+    item_converter: PlainLineItemConverter,
+}
+
+impl PlainLineConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: PlainLineItemConverter::new(options),
+        }
+    }
+}
+
+impl LineConverter for PlainLineConverter {
+    type ItemConverter = PlainLineItemConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write_items(&self, items: Vec<LineItem>, writer: &mut dyn Write) -> io::Result<()> {
+        self.write_items_trimmed(items, writer)
+    }
+
+    fn write(&self, line: Line, writer: &mut dyn Write) -> io::Result<()> {
+        match line {
+            Line::Paragraph(items) if items.is_empty() => {}
+            Line::Paragraph(items) => {
+                write!(writer, "{}", self.options().indent)?;
+                self.write_items(items, writer)?;
+            }
+            Line::Conversation(items) => self.write_items(items, writer)?,
+            Line::Quotation(items) => {
+                write!(writer, "{0}{0}", self.options().indent)?;
+                self.write_items(items, writer)?;
+            }
+            Line::Comment(comment) | Line::BlockComment(comment) => {
+                if self.options().comments == CommentPolicy::Keep {
+                    for line in comment.lines() {
+                        writeln!(writer, "※{}", line)?;
+                    }
+                }
+                return Ok(());
+            }
+            Line::AuthorOnly(_) => return Ok(()),
+        }
+        write!(writer, "{}", self.line_separator())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::tokens::line_item::Terminator;
+
+    use crate::converter::{RubyPolicy, SesamePolicy};
+
+    use super::*;
+
+    fn line() -> Line {
+        Line::Paragraph(vec![
+            LineItem::TextWithRuby(("吾輩".to_string(), "わがはい".to_string())),
+            LineItem::Text("は".to_string()),
+            LineItem::TextWithSesame(("猫".to_string(), '・')),
+            LineItem::Text("である".to_string()),
+            LineItem::EndOfSentence(Terminator::Exclamation("！".to_string())),
+        ])
+    }
+
+    #[test]
+    fn test_convert_paragraph() {
+        let result = PlainLineConverter::default().convert(line());
+        assert_eq!(result, "　吾輩（わがはい）は猫である！\n");
+    }
+
+    #[test]
+    fn test_convert_with_policies() {
+        let converter = PlainLineConverter::new(ConverterOptions {
+            ruby: RubyPolicy::Reading,
+            sesame: SesamePolicy::Bracket,
+            indent: "".to_string(),
+            ..Default::default()
+        });
+        assert_eq!(converter.convert(line()), "わがはいは〈猫〉である！\n");
+        let converter = PlainLineConverter::new(ConverterOptions {
+            ruby: RubyPolicy::Strip,
+            ..Default::default()
+        });
+        assert_eq!(converter.convert(line()), "　吾輩は猫である！\n");
+    }
+
+    #[test]
+    fn test_convert_comment() {
+        let line = Line::BlockComment("一行目\n二行目".to_string());
+        let converter = PlainLineConverter::new(ConverterOptions {
+            comments: CommentPolicy::Keep,
+            ..Default::default()
+        });
+        assert_eq!(converter.convert(line.clone()), "※一行目\n※二行目\n");
+        assert_eq!(PlainLineConverter::default().convert(line), "");
+    }
+}
@@ -0,0 +1,72 @@
+use rstrial_parser::tokens::{line_item::Terminator, LineItem};
+
+use crate::converter::{CommentPolicy, ConverterOptions, LineItemConverter};
+
+/// Converts items into text without any markup.
+///
+/// Plain text has no notation for ruby nor emphasis, so ruby kept by the ruby policy is
+/// parenthesized and sesame kept by the sesame policy is dropped.
+#[derive(Default)]
+pub struct PlainLineItemConverter {
+    options: ConverterOptions,
+}
+
+impl PlainLineItemConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl LineItemConverter for PlainLineItemConverter {
+    fn options(&self) -> &ConverterOptions {
+        &self.options
+    }
+
+    fn convert(&self, item: LineItem) -> String {
+        match item {
+            LineItem::Text(text) => text,
+            LineItem::Comma(comma) => comma,
+            LineItem::Comment(comment) => match self.options.comments {
+                CommentPolicy::Keep => format!("（※{comment}）"),
+                CommentPolicy::Drop => "".to_string(),
+            },
+            LineItem::TextWithRuby((text, ruby)) => format!("{text}（{ruby}）"),
+            LineItem::EndOfSentence(Terminator::Normal(terminator)) => terminator,
+            LineItem::EndOfSentence(Terminator::Exclamation(terminator)) => {
+                format!("{}　", terminator)
+            }
+            LineItem::EndOfSection(_) => "".to_string(),
+            LineItem::TextWithSesame((text, _)) => text,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_rich_text() {
+        let item = LineItem::TextWithRuby(("吾輩".to_string(), "わがはい".to_string()));
+        let result = PlainLineItemConverter::default().convert(item);
+        assert_eq!(result, "吾輩（わがはい）");
+    }
+
+    #[test]
+    fn test_convert_text_with_sesame() {
+        let item = LineItem::TextWithSesame(("まだ".to_string(), '・'));
+        let result = PlainLineItemConverter::default().convert(item);
+        assert_eq!(result, "まだ");
+    }
+
+    #[test]
+    fn test_convert_comment() {
+        let item = LineItem::Comment("要確認".to_string());
+        let converter = PlainLineItemConverter::new(ConverterOptions {
+            comments: CommentPolicy::Keep,
+            ..Default::default()
+        });
+        assert_eq!(converter.convert(item.clone()), "（※要確認）");
+        assert_eq!(PlainLineItemConverter::default().convert(item), "");
+    }
+}
@@ -0,0 +1,87 @@
+use std::io::{self, Write};
+
+use log::info;
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{is_published, ConverterOptions, ManuscriptConverter, SectionConverter};
+
+use super::section_converter::PlainSectionConverter;
+
+/// Converts a manuscript into plain text for word processors, screen readers and proofreading.
+///
+/// Titles are written as they are. Scenes are separated by the scene separator,
+/// or only by a blank line when the separator is empty.
+#[derive(Default)]
+pub struct PlainManuscriptConverter {
+    item_converter: PlainSectionConverter,
+}
+
+impl PlainManuscriptConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: PlainSectionConverter::new(options),
+        }
+    }
+}
+
+impl ManuscriptConverter for PlainManuscriptConverter {
+    type ItemConverter = PlainSectionConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(
+        &self,
+        sections: impl IntoIterator<Item = Section>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        let mut previous_was_scene = false;
+        for (index, section) in sections.into_iter().filter(is_published).enumerate() {
+            info!("convert: {:?}", section);
+            let is_scene = matches!(section, Section::Scene(_, _));
+            match (&section, previous_was_scene) {
+                _ if index == 0 => {}
+                (Section::Scene(_, _), true) if !self.options().scene_separator.is_empty() => {
+                    write!(writer, "\n{}\n\n", self.options().scene_separator)?
+                }
+                _ => writeln!(writer)?,
+            }
+            self.item_converter().write(section, writer)?;
+            previous_was_scene = is_scene;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::ManuscriptParser;
+
+    use super::*;
+
+    const INPUT: &str = "# 吾輩は猫である\n\n```\n{吾輩|わがはい}は猫である。\n// メモ\n```\n```\n「名前は{まだ|.}無い」\n```\n";
+
+    #[test]
+    fn test_convert() {
+        let result =
+            PlainManuscriptConverter::default().convert(ManuscriptParser::new(INPUT).collect());
+        assert_eq!(
+            result,
+            "吾輩は猫である\n\n　吾輩（わがはい）は猫である。\n\n†\n\n「名前はまだ無い」\n"
+        );
+    }
+
+    #[test]
+    fn test_convert_without_separator() {
+        let converter = PlainManuscriptConverter::new(ConverterOptions {
+            scene_separator: "".to_string(),
+            ..Default::default()
+        });
+        let result = converter.convert(ManuscriptParser::new(INPUT).collect());
+        assert_eq!(
+            result,
+            "吾輩は猫である\n\n　吾輩（わがはい）は猫である。\n\n「名前はまだ無い」\n"
+        );
+    }
+}
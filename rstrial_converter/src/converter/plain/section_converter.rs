@@ -0,0 +1,37 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{ConverterOptions, LineConverter, SectionConverter};
+
+use super::line_converter::PlainLineConverter;
+
+#[derive(Default)]
+pub struct PlainSectionConverter {
+    item_converter: PlainLineConverter,
+}
+
+impl PlainSectionConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: PlainLineConverter::new(options),
+        }
+    }
+}
+
+impl SectionConverter for PlainSectionConverter {
+    type ItemConverter = PlainLineConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(&self, section: Section, writer: &mut dyn Write) -> io::Result<()> {
+        match section {
+            Section::Title(title) => writeln!(writer, "{}", title),
+            Section::Scene(_, body) => body
+                .into_iter()
+                .try_for_each(|line| self.item_converter().write(line, writer)),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use std::io::{self, Write};
+
+/// `catalog.yml` of a Re:VIEW project, which lists chapter files in the order of the book.
+#[derive(Debug, Default)]
+pub struct Catalog {
+    /// Paths of `.re` files relative to the project directory.
+    pub chapters: Vec<String>,
+}
+
+impl Catalog {
+    pub fn write(&self, writer: &mut dyn Write) -> io::Result<()> {
+        writeln!(writer, "PREDEF:\n\nCHAPS:")?;
+        for chapter in &self.chapters {
+            writeln!(
+                writer,
+                "  - \"{}\"",
+                chapter.replace('\\', "\\\\").replace('"', "\\\"")
+            )?;
+        }
+        writeln!(writer, "\nAPPENDIX:\n\nPOSTDEF:")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write() {
+        let catalog = Catalog {
+            chapters: vec![
+                "01_吾輩は猫である.re".to_string(),
+                "part2/02.re".to_string(),
+            ],
+        };
+        let mut output = vec![];
+        catalog.write(&mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "PREDEF:\n\nCHAPS:\n  - \"01_吾輩は猫である.re\"\n  - \"part2/02.re\"\n\nAPPENDIX:\n\nPOSTDEF:\n"
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::Line;
+
+use crate::converter::{CommentPolicy, ConverterOptions, LineConverter};
+
+use super::line_item_converter::ReviewLineItemConverter;
+
+/// Converts lines into Re:VIEW paragraphs. Paragraphs are indented by the style of the book,
+/// so the `indent` option is not used.
+#[derive(Default)]
+pub struct ReviewLineConverter {
+    item_converter: ReviewLineItemConverter,
+}
+
+impl ReviewLineConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: ReviewLineItemConverter::new(options),
+        }
+    }
+}
+
+impl LineConverter for ReviewLineConverter {
+    type ItemConverter = ReviewLineItemConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    // Consecutive lines are joined into one paragraph in Re:VIEW.
+    fn line_separator(&self) -> String {
+        "\n\n".to_string()
+    }
+
+    fn write(&self, line: Line, writer: &mut dyn Write) -> io::Result<()> {
+        match line {
+            Line::Paragraph(items) if items.is_empty() => write!(writer, "//blankline")?,
+            Line::Paragraph(items) => self.write_items(items, writer)?,
+            Line::Conversation(items) => {
+                writeln!(writer, "//noindent")?;
+                self.write_items(items, writer)?;
+            }
+            Line::Quotation(items) => {
+                writeln!(writer, "//quote{{")?;
+                self.write_items(items, writer)?;
+                write!(writer, "\n//}}")?;
+            }
+            Line::Comment(comment) | Line::BlockComment(comment) => {
+                if self.options().comments == CommentPolicy::Keep {
+                    for line in comment.lines() {
+                        writeln!(writer, "#@#{}", line)?;
+                    }
+                    writeln!(writer)?;
+                }
+                return Ok(());
+            }
+            Line::AuthorOnly(_) => return Ok(()),
+        }
+        write!(writer, "{}", self.line_separator())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::tokens::{line_item::Terminator, LineItem};
+
+    use super::*;
+
+    #[test]
+    fn test_convert_conversation() {
+        let line = Line::Conversation(vec![
+            LineItem::Text("「にゃあ".to_string()),
+            LineItem::EndOfSentence(Terminator::Normal("」".to_string())),
+        ]);
+        let result = ReviewLineConverter::default().convert(line);
+        assert_eq!(result, "//noindent\n「にゃあ」\n\n");
+    }
+
+    #[test]
+    fn test_convert_comment() {
+        let line = Line::Comment("メモ".to_string());
+        let converter = ReviewLineConverter::new(ConverterOptions {
+            comments: CommentPolicy::Keep,
+            ..Default::default()
+        });
+        assert_eq!(converter.convert(line.clone()), "#@#メモ\n\n");
+        assert_eq!(ReviewLineConverter::default().convert(line), "");
+    }
+}
@@ -0,0 +1,75 @@
+use rstrial_parser::tokens::{line_item::Terminator, LineItem};
+
+use crate::converter::{CommentPolicy, ConverterOptions, LineItemConverter};
+
+/// Converts items into Re:VIEW inline commands.
+#[derive(Default)]
+pub struct ReviewLineItemConverter {
+    options: ConverterOptions,
+}
+
+impl ReviewLineItemConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self { options }
+    }
+
+    /// Escapes text put in the braces of an inline command.
+    pub fn escape(text: &str) -> String {
+        text.replace('\\', "\\\\").replace('}', "\\}")
+    }
+
+    // `,` separates the base and the ruby of `@<ruby>`.
+    fn escape_ruby(text: &str) -> String {
+        Self::escape(text).replace(',', "\\,")
+    }
+}
+
+impl LineItemConverter for ReviewLineItemConverter {
+    fn options(&self) -> &ConverterOptions {
+        &self.options
+    }
+
+    fn convert(&self, item: LineItem) -> String {
+        match item {
+            LineItem::Text(text) => text,
+            LineItem::Comma(comma) => comma,
+            LineItem::Comment(comment) => match self.options.comments {
+                CommentPolicy::Keep => format!("@<comment>{{{}}}", Self::escape(&comment)),
+                CommentPolicy::Drop => "".to_string(),
+            },
+            LineItem::TextWithRuby((text, ruby)) => format!(
+                "@<ruby>{{{},{}}}",
+                Self::escape_ruby(&text),
+                Self::escape_ruby(&ruby)
+            ),
+            LineItem::EndOfSentence(Terminator::Normal(terminator)) => terminator,
+            LineItem::EndOfSentence(Terminator::Exclamation(terminator)) => {
+                format!("{}　", terminator)
+            }
+            LineItem::EndOfSection(_) => "".to_string(),
+            LineItem::TextWithSesame((text, _)) => format!("@<bou>{{{}}}", Self::escape(&text)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_rich_text() {
+        let item = LineItem::TextWithRuby(("漢字".to_string(), "かんじ".to_string()));
+        let result = ReviewLineItemConverter::default().convert(item);
+        assert_eq!(result, "@<ruby>{漢字,かんじ}");
+        let item = LineItem::TextWithRuby(("a,b".to_string(), "{c}".to_string()));
+        let result = ReviewLineItemConverter::default().convert(item);
+        assert_eq!(result, "@<ruby>{a\\,b,{c\\}}");
+    }
+
+    #[test]
+    fn test_convert_text_with_sesame() {
+        let item = LineItem::TextWithSesame(("まだ".to_string(), '・'));
+        let result = ReviewLineItemConverter::default().convert(item);
+        assert_eq!(result, "@<bou>{まだ}");
+    }
+}
@@ -0,0 +1,83 @@
+use std::io::{self, Write};
+
+use log::info;
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{is_published, ConverterOptions, ManuscriptConverter, SectionConverter};
+
+use super::section_converter::ReviewSectionConverter;
+
+/// Converts a manuscript into a chapter file of Re:VIEW.
+///
+/// The first title becomes the chapter heading `=` and later titles become sections `==`.
+/// List the converted files in `catalog.yml` with [`super::catalog::Catalog`].
+#[derive(Default)]
+pub struct ReviewManuscriptConverter {
+    item_converter: ReviewSectionConverter,
+}
+
+impl ReviewManuscriptConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: ReviewSectionConverter::new(options),
+        }
+    }
+}
+
+impl ManuscriptConverter for ReviewManuscriptConverter {
+    type ItemConverter = ReviewSectionConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(
+        &self,
+        sections: impl IntoIterator<Item = Section>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        let mut has_chapter = false;
+        let mut previous_was_scene = false;
+        for section in sections.into_iter().filter(is_published) {
+            info!("convert: {:?}", section);
+            let is_scene = matches!(section, Section::Scene(_, _));
+            match &section {
+                Section::Title(title) if has_chapter => writeln!(writer, "== {}\n", title)?,
+                Section::Title(_) => {
+                    has_chapter = true;
+                    self.item_converter().write(section, writer)?;
+                }
+                Section::Scene(_, _) => {
+                    if previous_was_scene {
+                        writeln!(
+                            writer,
+                            "//centering{{\n{}\n//}}\n",
+                            self.options().scene_separator
+                        )?;
+                    }
+                    self.item_converter().write(section, writer)?;
+                }
+            }
+            previous_was_scene = is_scene;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::ManuscriptParser;
+
+    use super::*;
+
+    #[test]
+    fn test_convert() {
+        let input = "# 吾輩は猫である\n\n```\n{吾輩|わがはい}は猫である。名前は{まだ|.}無い。\n```\n```\n「にゃあ」\n```\n# 二\n\n```\n見当がつかぬ。\n```\n";
+        let result =
+            ReviewManuscriptConverter::default().convert(ManuscriptParser::new(input).collect());
+        assert_eq!(
+            result,
+            "= 吾輩は猫である\n\n@<ruby>{吾輩,わがはい}は猫である。名前は@<bou>{まだ}無い。\n\n//centering{\n†\n//}\n\n//noindent\n「にゃあ」\n\n== 二\n\n見当がつかぬ。\n\n"
+        );
+    }
+}
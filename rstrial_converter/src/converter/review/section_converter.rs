@@ -0,0 +1,37 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{ConverterOptions, LineConverter, SectionConverter};
+
+use super::line_converter::ReviewLineConverter;
+
+#[derive(Default)]
+pub struct ReviewSectionConverter {
+    item_converter: ReviewLineConverter,
+}
+
+impl ReviewSectionConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: ReviewLineConverter::new(options),
+        }
+    }
+}
+
+impl SectionConverter for ReviewSectionConverter {
+    type ItemConverter = ReviewLineConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(&self, section: Section, writer: &mut dyn Write) -> io::Result<()> {
+        match section {
+            Section::Title(title) => writeln!(writer, "= {}\n", title),
+            Section::Scene(_, body) => body
+                .into_iter()
+                .try_for_each(|line| self.item_converter().write(line, writer)),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::Line;
+
+use crate::converter::{ConverterOptions, LineConverter, LineItemConverter};
+
+use super::line_item_converter::RstrialLineItemConverter;
+
+#[derive(Default)]
+pub struct RstrialLineConverter {
+    item_converter: RstrialLineItemConverter,
+}
+
+impl RstrialLineConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: RstrialLineItemConverter::new(options),
+        }
+    }
+}
+
+impl LineConverter for RstrialLineConverter {
+    type ItemConverter = RstrialLineItemConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(&self, line: Line, writer: &mut dyn Write) -> io::Result<()> {
+        match line {
+            Line::Paragraph(items) | Line::Conversation(items) | Line::Quotation(items) => {
+                for item in items {
+                    self.item_converter().write(item, writer)?;
+                }
+            }
+            Line::Comment(comment) => write!(writer, "//{}", comment)?,
+            Line::BlockComment(comment) => write!(writer, "/*{}*/", comment)?,
+            Line::AuthorOnly(lines) => {
+                write!(writer, ":::author{}", self.line_separator())?;
+                for line in lines {
+                    self.write(line, writer)?;
+                }
+                write!(writer, ":::")?;
+            }
+        }
+        write!(writer, "{}", self.line_separator())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::tokens::{line_item::Terminator, LineItem};
+
+    use super::*;
+
+    #[test]
+    fn test_convert_conversation() {
+        let line = Line::Conversation(vec![
+            LineItem::Text("「我が輩は".to_string()),
+            LineItem::Comma("、".to_string()),
+            LineItem::Comment("猫である。".to_string()),
+            LineItem::TextWithRuby(("名前".to_string(), "なまえ".to_string())),
+            LineItem::Text("はまだ無い".to_string()),
+            LineItem::EndOfSentence(Terminator::Normal("」".to_string())),
+        ]);
+        let result = RstrialLineConverter::default().convert(line);
+        assert_eq!(
+            result,
+            "「我が輩は、{#猫である。}{名前|なまえ}はまだ無い」\n"
+        );
+    }
+
+    #[test]
+    fn test_convert_comment() {
+        let line = Line::Comment(" 猫でなく犬にすることも検討".to_string());
+        let result = RstrialLineConverter::default().convert(line);
+        assert_eq!(result, "// 猫でなく犬にすることも検討\n");
+    }
+}
@@ -0,0 +1,73 @@
+use rstrial_parser::tokens::{line_item::Terminator, LineItem};
+
+use crate::converter::{ConverterOptions, LineItemConverter};
+
+#[derive(Default)]
+pub struct RstrialLineItemConverter {
+    options: ConverterOptions,
+}
+
+impl RstrialLineItemConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl LineItemConverter for RstrialLineItemConverter {
+    fn options(&self) -> &ConverterOptions {
+        &self.options
+    }
+
+    fn convert(&self, item: LineItem) -> String {
+        match item {
+            LineItem::Text(text) => escape(&text),
+            LineItem::Comma(comma) => escape(&comma),
+            LineItem::Comment(comment) => format!("{{#{}}}", escape(&comment)),
+            LineItem::TextWithRuby((text, ruby)) => {
+                format!("{{{}|{}}}", escape(&text), escape(&ruby))
+            }
+            LineItem::EndOfSentence(Terminator::Normal(terminator)) => terminator,
+            LineItem::EndOfSentence(Terminator::Exclamation(terminator)) => terminator,
+            LineItem::EndOfSection(section) => section,
+            LineItem::TextWithSesame((text, _)) => format!("{{{}|.}}", escape(&text)),
+        }
+    }
+}
+
+// Braces have no escape in the manuscript notation, so imported ones are written full-width.
+fn escape(text: &str) -> String {
+    text.replace('{', "｛").replace('}', "｝")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_comment() {
+        let item = LineItem::Comment("comment".to_string());
+        let result = RstrialLineItemConverter::default().convert(item);
+        assert_eq!(result, "{#comment}");
+    }
+
+    #[test]
+    fn test_convert_rich_text() {
+        let item = LineItem::TextWithRuby(("text".to_string(), "ruby".to_string()));
+        let result = RstrialLineItemConverter::default().convert(item);
+        assert_eq!(result, "{text|ruby}");
+    }
+
+    #[test]
+    fn test_convert_text_with_sesame() {
+        let item = LineItem::TextWithSesame(("text".to_string(), '・'));
+        let result = RstrialLineItemConverter::default().convert(item);
+        assert_eq!(result, "{text|.}");
+    }
+
+    #[test]
+    fn test_convert_braces() {
+        let item = LineItem::Text("猫は{犬}が嫌い".to_string());
+        let result = RstrialLineItemConverter::default().convert(item);
+        assert_eq!(result, "猫は｛犬｝が嫌い");
+    }
+}
@@ -0,0 +1,121 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{ConverterOptions, ManuscriptConverter, SectionConverter};
+
+use super::section_converter::{write_tags, RstrialSectionConverter};
+
+#[derive(Default)]
+pub struct RstrialManuscriptConverter {
+    item_converter: RstrialSectionConverter,
+}
+
+impl RstrialManuscriptConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: RstrialSectionConverter::new(options),
+        }
+    }
+}
+
+impl ManuscriptConverter for RstrialManuscriptConverter {
+    type ItemConverter = RstrialSectionConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(
+        &self,
+        sections: impl IntoIterator<Item = Section>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        // Tags inherited from a heading are written once, as the first `@tags` block under it,
+        // and a blank line separates them from the tags of the first scene.
+        let mut previous_was_title = false;
+        for (index, mut section) in sections.into_iter().enumerate() {
+            if index > 0 {
+                writeln!(writer)?;
+            }
+            if let Section::Scene(document, _) = &mut section {
+                let inherited_tags = std::mem::take(&mut document.inherited_tags);
+                document.tags.retain(|tag| !inherited_tags.contains(tag));
+                if previous_was_title && !(inherited_tags.is_empty() && document.tags.is_empty()) {
+                    write_tags(&inherited_tags, writer)?;
+                    if !document.tags.is_empty() {
+                        writeln!(writer)?;
+                    }
+                }
+            }
+            previous_was_title = matches!(section, Section::Title(_));
+            self.item_converter().write(section, writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::{AozoraParser, ManuscriptParser};
+
+    use crate::converter::aozora::manuscript_converter::AozoraManuscriptConverter;
+
+    use super::*;
+
+    #[test]
+    fn test_convert() {
+        let input = "# 表題\n\n@tags 導入 猫 mood:calm\n```第１シーン {#scene-1 pov=猫 draft}\n{吾輩|わがはい}は猫である{#犬のほうがいいかも}。名前は{まだ|.}無い。\n// 見当をつけるかどうか\n/* 没案\n吾輩は犬である。*/\n:::author\n名前はポチ。\n:::\n```\n\n```第2シーン\n「どうも{甘|うま}くかけないものだね！」\n```\n";
+        let result =
+            RstrialManuscriptConverter::default().convert(ManuscriptParser::new(input).collect());
+        assert_eq!(result, input);
+    }
+
+    #[test]
+    fn test_convert_heading_tags() {
+        let input = "# 第一章\n\n@tags 猫\n\n@tags pov:猫\n```第一シーン\n```\n\n@tags mood:tense\n```第二シーン\n```\n\n```第三シーン\n```\n\n# 第二章\n\n```第四シーン\n```\n";
+        let result =
+            RstrialManuscriptConverter::default().convert(ManuscriptParser::new(input).collect());
+        assert_eq!(result, input);
+    }
+
+    #[test]
+    fn test_convert_scene_tags_after_heading() {
+        let input = "# 章\n本文\n@tags X\n```s1\n```\n```s2\n```\n";
+        let sections = ManuscriptParser::new(input).collect::<Vec<Section>>();
+        let result = RstrialManuscriptConverter::default().convert(sections.clone());
+        assert_eq!(
+            result,
+            "# 章\n\n@tags\n\n@tags X\n```s1\n```\n\n```s2\n```\n"
+        );
+        assert_eq!(
+            ManuscriptParser::new(&result).collect::<Vec<Section>>(),
+            sections
+        );
+    }
+
+    #[test]
+    fn test_convert_from_aozora() {
+        let input = "```第１シーン\n{吾輩|わがはい}は猫である。名前は{まだ|.}無い！\n```\n\n```第2シーン\n「どうも{甘|うま}くかけないものだね！」\n```\n";
+        let aozora =
+            AozoraManuscriptConverter::default().convert(ManuscriptParser::new(input).collect());
+        let result =
+            RstrialManuscriptConverter::default().convert(AozoraParser::new(&aozora).collect());
+        assert_eq!(
+            result,
+            "```\n{吾輩|わがはい}は猫である。名前は{まだ|.}無い！\n```\n\n```\n「どうも{甘|うま}くかけないものだね！」\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_convert_braces_from_aozora() {
+        let sections = AozoraParser::new("\n\n　猫は{犬}が嫌いだ。\n").collect();
+        let result = RstrialManuscriptConverter::default().convert(sections);
+        assert_eq!(result, "```\n猫は｛犬｝が嫌いだ。\n```\n");
+        let reparsed = ManuscriptParser::new(&result).collect::<Vec<Section>>();
+        assert_eq!(
+            RstrialManuscriptConverter::default().convert(reparsed),
+            result
+        );
+    }
+}
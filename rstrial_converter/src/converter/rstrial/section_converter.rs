@@ -0,0 +1,65 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::{section::Section, tag::Tag};
+
+use crate::converter::{ConverterOptions, LineConverter, SectionConverter};
+
+use super::line_converter::RstrialLineConverter;
+
+#[derive(Default)]
+pub struct RstrialSectionConverter {
+    item_converter: RstrialLineConverter,
+}
+
+impl RstrialSectionConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: RstrialLineConverter::new(options),
+        }
+    }
+}
+
+// An empty `@tags` line still ends the tags of a heading.
+pub(crate) fn write_tags(tags: &[Tag], writer: &mut dyn Write) -> io::Result<()> {
+    match tags.is_empty() {
+        true => writeln!(writer, "@tags"),
+        false => writeln!(
+            writer,
+            "@tags {}",
+            tags.iter()
+                .map(|tag| tag.to_string())
+                .collect::<Vec<String>>()
+                .join(" ")
+        ),
+    }
+}
+
+impl SectionConverter for RstrialSectionConverter {
+    type ItemConverter = RstrialLineConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(&self, section: Section, writer: &mut dyn Write) -> io::Result<()> {
+        match section {
+            Section::Title(title) => writeln!(writer, "# {}", title),
+            Section::Scene(document, body) => {
+                if !document.tags.is_empty() {
+                    write_tags(&document.tags, writer)?;
+                }
+                if !document.characters.is_empty() {
+                    writeln!(writer, "@characters {}", document.characters.join(" "))?;
+                }
+                match document.attribute_list().as_str() {
+                    "" => writeln!(writer, "```{}", document.title)?,
+                    attributes => writeln!(writer, "```{} {}", document.title, attributes)?,
+                }
+                for line in body {
+                    self.item_converter().write(line, writer)?;
+                }
+                writeln!(writer, "```")
+            }
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use rstrial_parser::tokens::{line_item::Terminator, Line, LineItem};
+
+/// A line of a scene attributed for script formats.
+#[derive(Debug, PartialEq)]
+pub(crate) enum Attributed {
+    /// Dialogue with its speaker. Items are left in `「」`.
+    Dialogue(String, Vec<LineItem>),
+    Other(Line),
+}
+
+/// Finds the speaker of a line, in the order of
+/// an inline marker such as `{#@太郎}「……」`,
+/// a name of `@characters` put right before `「` such as `太郎「……」`,
+/// and the only character of the scene for lines in `「」`.
+pub(crate) fn attribute(line: Line, characters: &[String]) -> Attributed {
+    let (is_conversation, mut items) = match line {
+        Line::Conversation(items) => (true, items),
+        Line::Paragraph(items) => (false, items),
+        line => return Attributed::Other(line),
+    };
+    if let Some(LineItem::Comment(comment)) = items.first() {
+        if let Some(name) = comment.strip_prefix('@') {
+            let name = name.trim().to_string();
+            items.remove(0);
+            return Attributed::Dialogue(name, items);
+        }
+    }
+    if let Some(LineItem::Text(text)) = items.first() {
+        let mut names = characters.iter().collect::<Vec<&String>>();
+        names.sort_by_key(|name| std::cmp::Reverse(name.chars().count()));
+        for name in names {
+            if let Some(rest) = text.strip_prefix(name.as_str()) {
+                if rest.starts_with('「') {
+                    items[0] = LineItem::Text(rest.to_string());
+                    return Attributed::Dialogue(name.clone(), items);
+                }
+            }
+        }
+    }
+    match (is_conversation, characters) {
+        (true, [name]) => Attributed::Dialogue(name.clone(), items),
+        (true, _) => Attributed::Other(Line::Conversation(items)),
+        (false, _) => Attributed::Other(Line::Paragraph(items)),
+    }
+}
+
+/// Removes `「` and `」` enclosing dialogue.
+pub(crate) fn unquote(mut items: Vec<LineItem>) -> Vec<LineItem> {
+    let Some(LineItem::Text(text)) = items.first() else {
+        return items;
+    };
+    let Some(text) = text.strip_prefix('「') else {
+        return items;
+    };
+    match text.is_empty() {
+        true => {
+            items.remove(0);
+        }
+        false => items[0] = LineItem::Text(text.to_string()),
+    }
+    if let Some(LineItem::EndOfSentence(
+        Terminator::Normal(terminator) | Terminator::Exclamation(terminator),
+    )) = items.last_mut()
+    {
+        if let Some(rest) = terminator.strip_suffix('」') {
+            match rest.is_empty() {
+                true => {
+                    items.pop();
+                }
+                false => *terminator = rest.to_string(),
+            }
+        }
+    }
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(text: &str) -> LineItem {
+        LineItem::Text(text.to_string())
+    }
+
+    fn close(terminator: &str) -> LineItem {
+        LineItem::EndOfSentence(Terminator::Normal(terminator.to_string()))
+    }
+
+    #[test]
+    fn test_attribute() {
+        let characters = vec!["太郎".to_string(), "太郎丸".to_string()];
+        let line = Line::Conversation(vec![
+            LineItem::Comment("@花子".to_string()),
+            text("「にゃあ"),
+            close("」"),
+        ]);
+        assert_eq!(
+            attribute(line, &characters),
+            Attributed::Dialogue("花子".to_string(), vec![text("「にゃあ"), close("」")])
+        );
+        let line = Line::Paragraph(vec![text("太郎丸「にゃあ"), close("」")]);
+        assert_eq!(
+            attribute(line, &characters),
+            Attributed::Dialogue("太郎丸".to_string(), vec![text("「にゃあ"), close("」")])
+        );
+        let line = Line::Conversation(vec![text("「にゃあ"), close("」")]);
+        assert_eq!(
+            attribute(line.clone(), &characters),
+            Attributed::Other(line.clone())
+        );
+        assert_eq!(
+            attribute(line, &characters[..1]),
+            Attributed::Dialogue("太郎".to_string(), vec![text("「にゃあ"), close("」")])
+        );
+    }
+
+    #[test]
+    fn test_unquote() {
+        assert_eq!(
+            unquote(vec![text("「にゃあ"), close("！」")]),
+            vec![text("にゃあ"), close("！")]
+        );
+        assert_eq!(
+            unquote(vec![text("「にゃあ"), close("」")]),
+            vec![text("にゃあ")]
+        );
+        assert_eq!(
+            unquote(vec![text("にゃあ"), close("。")]),
+            vec![text("にゃあ"), close("。")]
+        );
+    }
+}
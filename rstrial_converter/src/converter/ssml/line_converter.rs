@@ -0,0 +1,170 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::{line_item::Terminator, Line, LineItem};
+
+use crate::converter::{xml, CommentPolicy, ConverterOptions, LineConverter, LineItemConverter};
+
+use super::line_item_converter::SsmlLineItemConverter;
+
+#[derive(Default)]
+pub struct SsmlLineConverter {
+    item_converter: SsmlLineItemConverter,
+}
+
+impl SsmlLineConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: SsmlLineItemConverter::new(options),
+        }
+    }
+}
+
+// Splits items into sentences at terminators. Spaces put after exclamations are not read.
+fn sentences(items: Vec<LineItem>) -> Vec<Vec<LineItem>> {
+    let mut sentences = vec![];
+    let mut sentence = vec![];
+    for item in items {
+        let item = match item {
+            LineItem::Text(text) if sentence.is_empty() => {
+                let text = text.trim_start_matches([' ', '　']);
+                if text.is_empty() {
+                    continue;
+                }
+                LineItem::Text(text.to_string())
+            }
+            item => item,
+        };
+        let is_end = matches!(item, LineItem::EndOfSentence(_));
+        sentence.push(item);
+        if is_end {
+            sentences.push(std::mem::take(&mut sentence));
+        }
+    }
+    if !sentence.is_empty() {
+        sentences.push(sentence);
+    }
+    sentences
+}
+
+// Questions are read with a rising pitch and exclamations loudly, including `！」`.
+fn prosody(sentence: &[LineItem]) -> Option<&'static str> {
+    let terminator = match sentence.last() {
+        Some(LineItem::EndOfSentence(
+            Terminator::Normal(terminator) | Terminator::Exclamation(terminator),
+        )) => terminator,
+        _ => return None,
+    };
+    let question = terminator.contains(['？', '?']);
+    let exclamation = terminator.contains(['！', '!']);
+    match (question, exclamation) {
+        (true, true) => Some("pitch=\"high\" volume=\"loud\""),
+        (true, false) => Some("pitch=\"high\""),
+        (false, true) => Some("volume=\"loud\""),
+        (false, false) => None,
+    }
+}
+
+impl LineConverter for SsmlLineConverter {
+    type ItemConverter = SsmlLineItemConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write_items(&self, items: Vec<LineItem>, writer: &mut dyn Write) -> io::Result<()> {
+        let items = items
+            .into_iter()
+            .map(|item| self.options().apply_policies(item))
+            .collect();
+        for sentence in sentences(items) {
+            let prosody = prosody(&sentence);
+            write!(writer, "<s>")?;
+            if let Some(prosody) = prosody {
+                write!(writer, "<prosody {}>", prosody)?;
+            }
+            for item in sentence {
+                self.item_converter().write(item, writer)?;
+            }
+            if prosody.is_some() {
+                write!(writer, "</prosody>")?;
+            }
+            write!(writer, "</s>")?;
+        }
+        Ok(())
+    }
+
+    fn write(&self, line: Line, writer: &mut dyn Write) -> io::Result<()> {
+        match line {
+            Line::Paragraph(items) if items.is_empty() => {
+                write!(writer, "<break strength=\"strong\"/>")?
+            }
+            Line::Paragraph(items) => {
+                write!(writer, "<p>")?;
+                self.write_items(items, writer)?;
+                write!(writer, "</p>")?;
+            }
+            Line::Conversation(items) => match &self.options().dialogue_voice {
+                Some(voice) => {
+                    write!(writer, "<p><voice name=\"{}\">", xml::escape(voice))?;
+                    self.write_items(items, writer)?;
+                    write!(writer, "</voice></p>")?;
+                }
+                None => {
+                    write!(writer, "<p>")?;
+                    self.write_items(items, writer)?;
+                    write!(writer, "</p>")?;
+                }
+            },
+            Line::Quotation(items) => {
+                write!(writer, "<p><prosody rate=\"slow\">")?;
+                self.write_items(items, writer)?;
+                write!(writer, "</prosody></p>")?;
+            }
+            Line::Comment(comment) | Line::BlockComment(comment) => match self.options().comments {
+                CommentPolicy::Keep => write!(writer, "{}", xml::comment(&comment))?,
+                CommentPolicy::Drop => return Ok(()),
+            },
+            Line::AuthorOnly(_) => return Ok(()),
+        }
+        write!(writer, "{}", self.line_separator())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_paragraph() {
+        let line = Line::Paragraph(vec![
+            LineItem::Text("　本当".to_string()),
+            LineItem::EndOfSentence(Terminator::Exclamation("？".to_string())),
+            LineItem::Text("　本当だ".to_string()),
+            LineItem::EndOfSentence(Terminator::Exclamation("！".to_string())),
+            LineItem::Text("　".to_string()),
+            LineItem::Text("そうか".to_string()),
+            LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+        ]);
+        let result = SsmlLineConverter::default().convert(line);
+        assert_eq!(
+            result,
+            "<p><s><prosody pitch=\"high\">本当？</prosody></s><s><prosody volume=\"loud\">本当だ！</prosody></s><s>そうか。</s></p>\n"
+        );
+    }
+
+    #[test]
+    fn test_convert_conversation_with_voice() {
+        let converter = SsmlLineConverter::new(ConverterOptions {
+            dialogue_voice: Some("narrator-2".to_string()),
+            ..Default::default()
+        });
+        let line = Line::Conversation(vec![
+            LineItem::Text("「にゃあ".to_string()),
+            LineItem::EndOfSentence(Terminator::Normal("」".to_string())),
+        ]);
+        assert_eq!(
+            converter.convert(line),
+            "<p><voice name=\"narrator-2\"><s>「にゃあ」</s></voice></p>\n"
+        );
+    }
+}
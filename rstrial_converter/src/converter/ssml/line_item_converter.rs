@@ -0,0 +1,64 @@
+use rstrial_parser::tokens::{line_item::Terminator, LineItem};
+
+use crate::converter::{xml, CommentPolicy, ConverterOptions, LineItemConverter};
+
+#[derive(Default)]
+pub struct SsmlLineItemConverter {
+    options: ConverterOptions,
+}
+
+impl SsmlLineItemConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self { options }
+    }
+}
+
+impl LineItemConverter for SsmlLineItemConverter {
+    fn options(&self) -> &ConverterOptions {
+        &self.options
+    }
+
+    fn convert(&self, item: LineItem) -> String {
+        match item {
+            LineItem::Text(text) => xml::escape(&text),
+            LineItem::Comma(comma) => xml::escape(&comma),
+            LineItem::Comment(comment) => match self.options.comments {
+                CommentPolicy::Keep => xml::comment(&comment),
+                CommentPolicy::Drop => "".to_string(),
+            },
+            // The ruby is the reading, so the engine speaks it in place of the base text.
+            LineItem::TextWithRuby((text, ruby)) => format!(
+                "<sub alias=\"{}\">{}</sub>",
+                xml::escape(&ruby),
+                xml::escape(&text)
+            ),
+            LineItem::TextWithSesame((text, _)) => {
+                format!("<emphasis>{}</emphasis>", xml::escape(&text))
+            }
+            // Prosody of terminators is given by the line converter wrapping the sentence.
+            LineItem::EndOfSentence(
+                Terminator::Normal(terminator) | Terminator::Exclamation(terminator),
+            ) => xml::escape(&terminator),
+            LineItem::EndOfSection(_) => "".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_text_with_ruby() {
+        let item = LineItem::TextWithRuby(("吾輩".to_string(), "わがはい".to_string()));
+        let result = SsmlLineItemConverter::default().convert(item);
+        assert_eq!(result, "<sub alias=\"わがはい\">吾輩</sub>");
+    }
+
+    #[test]
+    fn test_convert_text_with_sesame() {
+        let item = LineItem::TextWithSesame(("まだ".to_string(), '﹅'));
+        let result = SsmlLineItemConverter::default().convert(item);
+        assert_eq!(result, "<emphasis>まだ</emphasis>");
+    }
+}
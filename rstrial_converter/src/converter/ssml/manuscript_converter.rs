@@ -0,0 +1,72 @@
+use std::io::{self, Write};
+
+use log::info;
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{is_published, ConverterOptions, ManuscriptConverter, SectionConverter};
+
+use super::section_converter::SsmlSectionConverter;
+
+/// Converts a manuscript into an SSML document for text-to-speech engines.
+/// Ruby is read by its reading, and scene separators become pauses instead of being read.
+#[derive(Default)]
+pub struct SsmlManuscriptConverter {
+    item_converter: SsmlSectionConverter,
+}
+
+impl SsmlManuscriptConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: SsmlSectionConverter::new(options),
+        }
+    }
+}
+
+impl ManuscriptConverter for SsmlManuscriptConverter {
+    type ItemConverter = SsmlSectionConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(
+        &self,
+        sections: impl IntoIterator<Item = Section>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        writeln!(
+            writer,
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<speak version=\"1.1\" xmlns=\"http://www.w3.org/2001/10/synthesis\" xml:lang=\"ja-JP\">"
+        )?;
+        let mut previous_was_scene = false;
+        for section in sections.into_iter().filter(is_published) {
+            info!("convert: {:?}", section);
+            let is_scene = matches!(section, Section::Scene(_, _));
+            if let (Section::Scene(_, _), true) = (&section, previous_was_scene) {
+                writeln!(writer, "<break strength=\"x-strong\"/>")?;
+            }
+            self.item_converter().write(section, writer)?;
+            previous_was_scene = is_scene;
+        }
+        writeln!(writer, "</speak>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::ManuscriptParser;
+
+    use super::*;
+
+    const INPUT: &str = "# 吾輩は猫である\n\n```第１シーン\n{吾輩|わがはい}は猫である。名前は{まだ|.}無い。\n```\n```第２シーン {#second}\n「にゃあ！」\n```\n";
+
+    #[test]
+    fn test_convert() {
+        let result =
+            SsmlManuscriptConverter::default().convert(ManuscriptParser::new(INPUT).collect());
+        assert_eq!(
+            result,
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<speak version=\"1.1\" xmlns=\"http://www.w3.org/2001/10/synthesis\" xml:lang=\"ja-JP\">\n<p><s>吾輩は猫である</s></p>\n<break time=\"2s\"/>\n<mark name=\"第１シーン\"/>\n<p><s><sub alias=\"わがはい\">吾輩</sub>は猫である。</s><s>名前は<emphasis>まだ</emphasis>無い。</s></p>\n<break strength=\"x-strong\"/>\n<mark name=\"second\"/>\n<p><s><prosody volume=\"loud\">「にゃあ！」</prosody></s></p>\n</speak>\n"
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{xml, ConverterOptions, LineConverter, SectionConverter};
+
+use super::line_converter::SsmlLineConverter;
+
+#[derive(Default)]
+pub struct SsmlSectionConverter {
+    item_converter: SsmlLineConverter,
+}
+
+impl SsmlSectionConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: SsmlLineConverter::new(options),
+        }
+    }
+}
+
+impl SectionConverter for SsmlSectionConverter {
+    type ItemConverter = SsmlLineConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(&self, section: Section, writer: &mut dyn Write) -> io::Result<()> {
+        match section {
+            Section::Title(title) => writeln!(
+                writer,
+                "<p><s>{}</s></p>\n<break time=\"2s\"/>",
+                xml::escape(&title)
+            ),
+            Section::Scene(document, body) => {
+                // Marks let players jump to scenes by their IDs.
+                if let Some(id) = document.scene_id() {
+                    writeln!(writer, "<mark name=\"{}\"/>", xml::escape(&id))?;
+                }
+                body.into_iter()
+                    .try_for_each(|line| self.item_converter().write(line, writer))
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::ops::RangeInclusive;
+
+/// Wraps runs of characters matched by `is_target` with `wrap` if the length of the run is in
+/// `lengths`, for setting them upright in vertical writing.
+pub(crate) fn apply(
+    text: &str,
+    is_target: fn(char) -> bool,
+    lengths: RangeInclusive<usize>,
+    wrap: impl Fn(&str) -> String,
+) -> String {
+    let mut converted = String::with_capacity(text.len());
+    let mut run = String::new();
+    for c in text.chars().chain(std::iter::once('\0')) {
+        if is_target(c) {
+            run.push(c);
+            continue;
+        }
+        match lengths.contains(&run.chars().count()) {
+            true => converted.push_str(&wrap(&half_width(&run))),
+            false => converted.push_str(&run),
+        }
+        run.clear();
+        if c != '\0' {
+            converted.push(c);
+        }
+    }
+    converted
+}
+
+/// Targets of runs of digits such as `12`.
+pub(crate) fn is_digit(c: char) -> bool {
+    c.is_ascii_digit()
+}
+
+/// Targets of runs of exclamations such as `！？`.
+pub(crate) fn is_exclamation(c: char) -> bool {
+    matches!(c, '!' | '?' | '！' | '？')
+}
+
+fn half_width(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '！' => '!',
+            '？' => '?',
+            c => c,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let wrap = |run: &str| format!("[{}]", run);
+        assert_eq!(apply("1月12日123", is_digit, 1..=2, wrap), "[1]月[12]日123");
+        assert_eq!(apply("！？", is_exclamation, 2..=2, wrap), "[!?]");
+        assert_eq!(apply("！", is_exclamation, 2..=2, wrap), "！");
+    }
+}
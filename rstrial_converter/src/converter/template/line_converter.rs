@@ -0,0 +1,102 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::{Line, LineItem};
+
+use crate::converter::{write_to_string, CommentPolicy, ConverterOptions, LineConverter};
+
+use super::{
+    line_item_converter::TemplateLineItemConverter,
+    snippets::{render, Snippets},
+};
+
+#[derive(Default)]
+pub struct TemplateLineConverter {
+    item_converter: TemplateLineItemConverter,
+}
+
+impl TemplateLineConverter {
+    pub fn new(options: ConverterOptions, snippets: Snippets) -> Self {
+        Self {
+            item_converter: TemplateLineItemConverter::new(options, snippets),
+        }
+    }
+
+    pub fn snippets(&self) -> &Snippets {
+        self.item_converter.snippets()
+    }
+
+    fn write_snippet(
+        &self,
+        snippet: &str,
+        items: Vec<LineItem>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        let text = write_to_string(|writer| self.write_items(items, writer));
+        let indent = self.snippets().escape.apply(&self.options().indent);
+        write!(
+            writer,
+            "{}",
+            render(snippet, &[("text", &text), ("indent", &indent)])
+        )
+    }
+}
+
+impl LineConverter for TemplateLineConverter {
+    type ItemConverter = TemplateLineItemConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write_items(&self, items: Vec<LineItem>, writer: &mut dyn Write) -> io::Result<()> {
+        self.write_items_trimmed(items, writer)
+    }
+
+    // Snippets end lines by themselves.
+    fn write(&self, line: Line, writer: &mut dyn Write) -> io::Result<()> {
+        let snippets = self.snippets();
+        match line {
+            Line::Paragraph(items) if items.is_empty() => write!(writer, "{}", snippets.blank),
+            Line::Paragraph(items) => self.write_snippet(&snippets.paragraph, items, writer),
+            Line::Conversation(items) => self.write_snippet(&snippets.conversation, items, writer),
+            Line::Quotation(items) => self.write_snippet(&snippets.quotation, items, writer),
+            Line::Comment(comment) | Line::BlockComment(comment) => match self.options().comments {
+                CommentPolicy::Keep => self.write_comment(comment, writer),
+                CommentPolicy::Drop => Ok(()),
+            },
+            Line::AuthorOnly(_) => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::tokens::line_item::Terminator;
+
+    use super::*;
+
+    #[test]
+    fn test_convert() {
+        let converter = TemplateLineConverter::new(
+            ConverterOptions {
+                comments: CommentPolicy::Keep,
+                ..Default::default()
+            },
+            Snippets {
+                conversation: "<p class=\"serif\">{text}</p>\n".to_string(),
+                comment: "<!-- {comment} -->".to_string(),
+                ..Default::default()
+            },
+        );
+        let line = Line::Conversation(vec![
+            LineItem::Text("「にゃあ".to_string()),
+            LineItem::EndOfSentence(Terminator::Normal("」".to_string())),
+        ]);
+        assert_eq!(
+            converter.convert(line),
+            "<p class=\"serif\">「にゃあ」</p>\n"
+        );
+        let line = Line::Comment(" メモ".to_string());
+        assert_eq!(converter.convert(line), "<!--  メモ -->\n");
+    }
+}
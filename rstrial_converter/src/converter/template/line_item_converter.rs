@@ -0,0 +1,81 @@
+use rstrial_parser::tokens::{line_item::Terminator, LineItem};
+
+use crate::converter::{CommentPolicy, ConverterOptions, LineItemConverter};
+
+use super::snippets::{render, Snippets};
+
+#[derive(Default)]
+pub struct TemplateLineItemConverter {
+    options: ConverterOptions,
+    snippets: Snippets,
+}
+
+impl TemplateLineItemConverter {
+    pub fn new(options: ConverterOptions, snippets: Snippets) -> Self {
+        Self { options, snippets }
+    }
+
+    pub fn snippets(&self) -> &Snippets {
+        &self.snippets
+    }
+
+    fn escape(&self, text: &str) -> String {
+        self.snippets.escape.apply(text)
+    }
+}
+
+impl LineItemConverter for TemplateLineItemConverter {
+    fn options(&self) -> &ConverterOptions {
+        &self.options
+    }
+
+    fn convert(&self, item: LineItem) -> String {
+        match item {
+            LineItem::Text(text) => self.escape(&text),
+            LineItem::Comma(comma) => self.escape(&comma),
+            LineItem::Comment(comment) => match self.options.comments {
+                CommentPolicy::Keep => render(
+                    &self.snippets.comment,
+                    &[("comment", &self.escape(&comment))],
+                ),
+                CommentPolicy::Drop => "".to_string(),
+            },
+            LineItem::TextWithRuby((text, ruby)) => render(
+                &self.snippets.ruby,
+                &[("text", &self.escape(&text)), ("ruby", &self.escape(&ruby))],
+            ),
+            LineItem::TextWithSesame((text, _)) => {
+                render(&self.snippets.emphasis, &[("text", &self.escape(&text))])
+            }
+            LineItem::EndOfSentence(Terminator::Normal(terminator)) => self.escape(&terminator),
+            LineItem::EndOfSentence(Terminator::Exclamation(terminator)) => {
+                format!("{}　", self.escape(&terminator))
+            }
+            LineItem::EndOfSection(_) => "".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::converter::template::snippets::Escape;
+
+    use super::*;
+
+    #[test]
+    fn test_convert_text_with_ruby() {
+        let converter = TemplateLineItemConverter::new(
+            ConverterOptions::default(),
+            Snippets {
+                escape: Escape::Xml,
+                ruby: "<ruby>{text}<rt>{ruby}</rt></ruby>".to_string(),
+                ..Default::default()
+            },
+        );
+        let item = LineItem::TextWithRuby(("<吾輩>".to_string(), "わがはい".to_string()));
+        assert_eq!(
+            converter.convert(item),
+            "<ruby>&lt;吾輩&gt;<rt>わがはい</rt></ruby>"
+        );
+    }
+}
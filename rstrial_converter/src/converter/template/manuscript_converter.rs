@@ -0,0 +1,122 @@
+use std::io::{self, Write};
+
+use log::info;
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{is_published, ConverterOptions, ManuscriptConverter, SectionConverter};
+
+use super::{
+    section_converter::TemplateSectionConverter,
+    snippets::{render, Snippets},
+};
+
+/// Converts a manuscript through the snippets of a user-supplied template,
+/// so that in-house formats need no converter of their own.
+#[derive(Default)]
+pub struct TemplateManuscriptConverter {
+    item_converter: TemplateSectionConverter,
+}
+
+impl TemplateManuscriptConverter {
+    pub fn new(options: ConverterOptions, snippets: Snippets) -> Self {
+        Self {
+            item_converter: TemplateSectionConverter::new(options, snippets),
+        }
+    }
+
+    pub fn snippets(&self) -> &Snippets {
+        self.item_converter.snippets()
+    }
+}
+
+impl ManuscriptConverter for TemplateManuscriptConverter {
+    type ItemConverter = TemplateSectionConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(
+        &self,
+        sections: impl IntoIterator<Item = Section>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        let escape = self.snippets().escape;
+        let mut sections = sections.into_iter().filter(is_published).peekable();
+        let title = match sections.peek() {
+            Some(Section::Title(title)) => escape.apply(title),
+            _ => "".to_string(),
+        };
+        write!(
+            writer,
+            "{}",
+            render(&self.snippets().header, &[("title", &title)])
+        )?;
+        let mut previous_was_scene = false;
+        for section in sections {
+            info!("convert: {:?}", section);
+            let is_scene = matches!(section, Section::Scene(_, _));
+            if let (Section::Scene(_, _), true) = (&section, previous_was_scene) {
+                let separator = escape.apply(&self.options().scene_separator);
+                write!(
+                    writer,
+                    "{}",
+                    render(&self.snippets().separator, &[("separator", &separator)])
+                )?;
+            }
+            self.item_converter().write(section, writer)?;
+            previous_was_scene = is_scene;
+        }
+        write!(
+            writer,
+            "{}",
+            render(&self.snippets().footer, &[("title", &title)])
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::ManuscriptParser;
+
+    use crate::converter::template::snippets::Escape;
+
+    use super::*;
+
+    const INPUT: &str = "# 吾輩は猫である\n\n```第１シーン\n{吾輩|わがはい}は猫である。名前は{まだ|.}無い。\n```\n```第２シーン {#second}\n「にゃあ」\n```\n";
+
+    #[test]
+    fn test_convert() {
+        let result =
+            TemplateManuscriptConverter::default().convert(ManuscriptParser::new(INPUT).collect());
+        assert_eq!(
+            result,
+            "吾輩は猫である\n\n　吾輩（わがはい）は猫である。名前はまだ無い。\n\n†\n\n「にゃあ」\n"
+        );
+    }
+
+    #[test]
+    fn test_convert_with_snippets() {
+        let converter = TemplateManuscriptConverter::new(
+            ConverterOptions::default(),
+            Snippets {
+                escape: Escape::Xml,
+                header: "<article title=\"{title}\">\n".to_string(),
+                footer: "</article>\n".to_string(),
+                title: "<h1>{title}</h1>\n".to_string(),
+                scene: "<section id=\"{id}\">\n{body}</section>\n".to_string(),
+                separator: "<hr>\n".to_string(),
+                paragraph: "<p>{text}</p>\n".to_string(),
+                conversation: "<p class=\"serif\">{text}</p>\n".to_string(),
+                ruby: "<ruby>{text}<rt>{ruby}</rt></ruby>".to_string(),
+                emphasis: "<em>{text}</em>".to_string(),
+                ..Default::default()
+            },
+        );
+        let result = converter.convert(ManuscriptParser::new(INPUT).collect());
+        assert_eq!(
+            result,
+            "<article title=\"吾輩は猫である\">\n<h1>吾輩は猫である</h1>\n<section id=\"第１シーン\">\n<p><ruby>吾輩<rt>わがはい</rt></ruby>は猫である。名前は<em>まだ</em>無い。</p>\n</section>\n<hr>\n<section id=\"second\">\n<p class=\"serif\">「にゃあ」</p>\n</section>\n</article>\n"
+        );
+    }
+}
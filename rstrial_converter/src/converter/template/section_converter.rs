@@ -0,0 +1,65 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{write_to_string, ConverterOptions, LineConverter, SectionConverter};
+
+use super::{
+    line_converter::TemplateLineConverter,
+    snippets::{render, Snippets},
+};
+
+#[derive(Default)]
+pub struct TemplateSectionConverter {
+    item_converter: TemplateLineConverter,
+}
+
+impl TemplateSectionConverter {
+    pub fn new(options: ConverterOptions, snippets: Snippets) -> Self {
+        Self {
+            item_converter: TemplateLineConverter::new(options, snippets),
+        }
+    }
+
+    pub fn snippets(&self) -> &Snippets {
+        self.item_converter.snippets()
+    }
+}
+
+impl SectionConverter for TemplateSectionConverter {
+    type ItemConverter = TemplateLineConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(&self, section: Section, writer: &mut dyn Write) -> io::Result<()> {
+        let escape = self.snippets().escape;
+        match section {
+            Section::Title(title) => write!(
+                writer,
+                "{}",
+                render(&self.snippets().title, &[("title", &escape.apply(&title))])
+            ),
+            Section::Scene(document, body) => {
+                let body = write_to_string(|writer| {
+                    body.into_iter()
+                        .try_for_each(|line| self.item_converter().write(line, writer))
+                });
+                let id = escape.apply(&document.scene_id().unwrap_or_default());
+                write!(
+                    writer,
+                    "{}",
+                    render(
+                        &self.snippets().scene,
+                        &[
+                            ("title", &escape.apply(&document.title)),
+                            ("id", &id),
+                            ("body", &body),
+                        ],
+                    )
+                )
+            }
+        }
+    }
+}
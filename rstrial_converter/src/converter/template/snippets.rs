@@ -0,0 +1,123 @@
+use serde::Deserialize;
+
+use crate::converter::xml;
+
+/// How values put into snippets are escaped.
+#[derive(Debug, PartialEq, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Escape {
+    /// Put values as they are.
+    #[default]
+    None,
+    /// Escape characters special in XML and HTML.
+    Xml,
+}
+
+impl Escape {
+    pub fn apply(&self, text: &str) -> String {
+        match self {
+            Escape::None => text.to_string(),
+            Escape::Xml => xml::escape(text),
+        }
+    }
+}
+
+/// Snippets of a user-supplied template, in which placeholders such as `{text}` are replaced.
+///
+/// The defaults render plain text, and a template file only has to give the snippets it changes.
+#[derive(Debug, PartialEq, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Snippets {
+    /// Extension of output files such as `html`. Output files keep the extension of the source if not given.
+    pub extension: Option<String>,
+    pub escape: Escape,
+    /// Beginning of the output with `{title}`, the first title of the manuscript.
+    pub header: String,
+    /// End of the output with `{title}`.
+    pub footer: String,
+    /// Heading of a `# ` title with `{title}`.
+    pub title: String,
+    /// Wrapper of a scene with `{title}`, `{id}` and `{body}`.
+    pub scene: String,
+    /// Put between scenes with `{separator}`.
+    pub separator: String,
+    /// Paragraph with `{text}` and `{indent}`.
+    pub paragraph: String,
+    /// Line in `「」` with `{text}` and `{indent}`.
+    pub conversation: String,
+    /// Quotation with `{text}` and `{indent}`.
+    pub quotation: String,
+    /// Empty paragraph.
+    pub blank: String,
+    /// Ruby with `{text}` and `{ruby}`.
+    pub ruby: String,
+    /// Sesame with `{text}`.
+    pub emphasis: String,
+    /// Comment kept by the comment policy with `{comment}`. Line comments are followed by a line break.
+    pub comment: String,
+}
+
+impl Default for Snippets {
+    fn default() -> Self {
+        Self {
+            extension: None,
+            escape: Escape::default(),
+            header: "".to_string(),
+            footer: "".to_string(),
+            title: "{title}\n\n".to_string(),
+            scene: "{body}".to_string(),
+            separator: "\n{separator}\n\n".to_string(),
+            paragraph: "{indent}{text}\n".to_string(),
+            conversation: "{text}\n".to_string(),
+            quotation: "{indent}{indent}{text}\n".to_string(),
+            blank: "\n".to_string(),
+            ruby: "{text}（{ruby}）".to_string(),
+            emphasis: "{text}".to_string(),
+            comment: "（※{comment}）".to_string(),
+        }
+    }
+}
+
+/// Replaces placeholders such as `{text}` in a snippet at once, so that values are never replaced again.
+/// Unknown placeholders are left as they are.
+pub fn render(snippet: &str, values: &[(&str, &str)]) -> String {
+    let mut rendered = String::with_capacity(snippet.len());
+    let mut rest = snippet;
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let value = rest.find('}').and_then(|end| {
+            values
+                .iter()
+                .find(|(name, _)| *name == &rest[1..end])
+                .map(|(_, value)| (end, value))
+        });
+        match value {
+            Some((end, value)) => {
+                rendered.push_str(value);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                rendered.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let values = [("text", "{ruby}"), ("ruby", "かんじ")];
+        assert_eq!(
+            render("<ruby>{text}<rt>{ruby}</rt></ruby>", &values),
+            "<ruby>{ruby}<rt>かんじ</rt></ruby>"
+        );
+        assert_eq!(render("{unknown} {text", &values), "{unknown} {text");
+    }
+}
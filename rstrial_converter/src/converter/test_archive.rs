@@ -0,0 +1,32 @@
+//! Helpers for tests of converters which write zip archives, such as DOCX, ODT and EPUB.
+
+use std::io::{Cursor, Read};
+
+use rstrial_parser::ManuscriptParser;
+use zip::ZipArchive;
+
+use crate::converter::ManuscriptConverter;
+
+/// Manuscript with two chapters, ruby, sesame and conversation.
+pub(crate) const INPUT: &str = "# 吾輩は猫である\n\n```一\n{吾輩|わがはい}は猫である。名前は{まだ|.}無い。\n```\n```二\n「にゃあ」\n```\n# 二章\n\n```三\nどこで生れたかとんと見当がつかぬ。\n```\n";
+
+pub(crate) fn convert(
+    converter: impl ManuscriptConverter,
+    input: &str,
+) -> ZipArchive<Cursor<Vec<u8>>> {
+    let mut output = vec![];
+    converter
+        .write(ManuscriptParser::new(input), &mut output)
+        .unwrap();
+    ZipArchive::new(Cursor::new(output)).unwrap()
+}
+
+pub(crate) fn read(archive: &mut ZipArchive<Cursor<Vec<u8>>>, name: &str) -> String {
+    let mut content = String::new();
+    archive
+        .by_name(name)
+        .unwrap_or_else(|_| panic!("missing {}", name))
+        .read_to_string(&mut content)
+        .unwrap();
+    content
+}
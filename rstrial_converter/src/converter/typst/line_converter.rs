@@ -0,0 +1,79 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::Line;
+
+use crate::converter::{CommentPolicy, ConverterOptions, LineConverter};
+
+use super::line_item_converter::TypstLineItemConverter;
+
+#[derive(Default)]
+pub struct TypstLineConverter {
+    item_converter: TypstLineItemConverter,
+}
+
+impl TypstLineConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: TypstLineItemConverter::new(options),
+        }
+    }
+}
+
+impl LineConverter for TypstLineConverter {
+    type ItemConverter = TypstLineItemConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn line_separator(&self) -> String {
+        "\n\n".to_string()
+    }
+
+    fn write(&self, line: Line, writer: &mut dyn Write) -> io::Result<()> {
+        match line {
+            Line::Paragraph(items) if items.is_empty() => write!(writer, "#v(1em)")?,
+            Line::Paragraph(items) => {
+                write!(
+                    writer,
+                    "{}",
+                    TypstLineItemConverter::escape(&self.options().indent)
+                )?;
+                self.write_items(items, writer)?;
+            }
+            Line::Conversation(items) => self.write_items(items, writer)?,
+            Line::Quotation(items) => {
+                write!(writer, "#pad(left: 2em)[")?;
+                self.write_items(items, writer)?;
+                write!(writer, "]")?;
+            }
+            Line::Comment(comment) | Line::BlockComment(comment) => {
+                if self.options().comments == CommentPolicy::Keep {
+                    for line in comment.lines() {
+                        writeln!(writer, "//{}", line)?;
+                    }
+                }
+                return Ok(());
+            }
+            Line::AuthorOnly(_) => return Ok(()),
+        }
+        write!(writer, "{}", self.line_separator())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::tokens::{line_item::Terminator, LineItem};
+
+    use super::*;
+
+    #[test]
+    fn test_convert_quotation() {
+        let line = Line::Quotation(vec![
+            LineItem::Text("「どうも".to_string()),
+            LineItem::EndOfSentence(Terminator::Normal("」".to_string())),
+        ]);
+        let result = TypstLineConverter::default().convert(line);
+        assert_eq!(result, "#pad(left: 2em)[「どうも」]\n\n");
+    }
+}
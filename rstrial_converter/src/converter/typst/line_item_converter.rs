@@ -0,0 +1,123 @@
+use rstrial_parser::tokens::{line_item::Terminator, LineItem};
+
+use crate::converter::{CommentPolicy, ConverterOptions, LineItemConverter};
+
+/// Converts items into Typst markup using the functions of the bundled template.
+#[derive(Default)]
+pub struct TypstLineItemConverter {
+    options: ConverterOptions,
+}
+
+impl TypstLineItemConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self { options }
+    }
+
+    /// Escapes characters which have special meanings in Typst markup.
+    ///
+    /// A leading `.` or `(` is also escaped, since it would continue a preceding `#ruby()` call.
+    pub fn escape(text: &str) -> String {
+        let mut escaped = String::with_capacity(text.len());
+        if text.starts_with(['.', '(']) {
+            escaped.push('\\');
+        }
+        for c in text.chars() {
+            if matches!(
+                c,
+                '\\' | '#'
+                    | '*'
+                    | '_'
+                    | '`'
+                    | '$'
+                    | '<'
+                    | '>'
+                    | '@'
+                    | '['
+                    | ']'
+                    | '~'
+                    | '/'
+                    | '='
+                    | '-'
+                    | '+'
+            ) {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        escaped
+    }
+
+    /// Formats a block comment. Typst block comments nest, so a space is put after every `/` and `*`
+    /// to leave neither `/*` nor `*/` in the text, even next to the delimiters.
+    pub fn comment(text: &str) -> String {
+        format!("/*{}*/", text.replace('/', "/ ").replace('*', "* "))
+    }
+
+    /// Formats a Typst string literal.
+    pub fn string(text: &str) -> String {
+        format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+impl LineItemConverter for TypstLineItemConverter {
+    fn options(&self) -> &ConverterOptions {
+        &self.options
+    }
+
+    fn convert(&self, item: LineItem) -> String {
+        match item {
+            LineItem::Text(text) => Self::escape(&text),
+            LineItem::Comma(comma) => Self::escape(&comma),
+            LineItem::Comment(comment) => match self.options.comments {
+                CommentPolicy::Keep => Self::comment(&comment),
+                CommentPolicy::Drop => "".to_string(),
+            },
+            LineItem::TextWithRuby((text, ruby)) => {
+                format!("#ruby({}, {})", Self::string(&text), Self::string(&ruby))
+            }
+            LineItem::EndOfSentence(Terminator::Normal(terminator)) => Self::escape(&terminator),
+            LineItem::EndOfSentence(Terminator::Exclamation(terminator)) => {
+                format!("{}　", Self::escape(&terminator))
+            }
+            LineItem::EndOfSection(_) => "".to_string(),
+            LineItem::TextWithSesame((text, _)) => format!("#kenten({})", Self::string(&text)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_text() {
+        let item = LineItem::Text("#猫 = *犬*".to_string());
+        let result = TypstLineItemConverter::default().convert(item);
+        assert_eq!(result, "\\#猫 \\= \\*犬\\*");
+        let item = LineItem::Text("(猫).".to_string());
+        let result = TypstLineItemConverter::default().convert(item);
+        assert_eq!(result, "\\(猫).");
+    }
+
+    #[test]
+    fn test_convert_rich_text() {
+        let item = LineItem::TextWithRuby(("吾輩".to_string(), "わが\"はい".to_string()));
+        let result = TypstLineItemConverter::default().convert(item);
+        assert_eq!(result, "#ruby(\"吾輩\", \"わが\\\"はい\")");
+    }
+
+    #[test]
+    fn test_convert_text_with_sesame() {
+        let item = LineItem::TextWithSesame(("まだ".to_string(), '・'));
+        let result = TypstLineItemConverter::default().convert(item);
+        assert_eq!(result, "#kenten(\"まだ\")");
+    }
+
+    #[test]
+    fn test_comment() {
+        assert_eq!(TypstLineItemConverter::comment("メモ"), "/*メモ*/");
+        assert_eq!(TypstLineItemConverter::comment("a/*b"), "/*a/ * b*/");
+        assert_eq!(TypstLineItemConverter::comment("a*/b"), "/*a* / b*/");
+        assert_eq!(TypstLineItemConverter::comment("a/"), "/*a/ */");
+    }
+}
@@ -0,0 +1,95 @@
+use std::io::{self, Write};
+
+use log::info;
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{is_published, ConverterOptions, ManuscriptConverter, SectionConverter};
+
+use super::{
+    line_item_converter::TypstLineItemConverter, section_converter::TypstSectionConverter,
+};
+
+/// The template put at the head of every converted document, so that output compiles as-is.
+pub const TYPST_TEMPLATE: &str = include_str!("template.typ");
+
+/// Converts a manuscript into a standalone Typst document.
+///
+/// Titles become headings and the first title also becomes the document title.
+/// Typst cannot typeset vertically yet, so the writing mode option is ignored.
+#[derive(Default)]
+pub struct TypstManuscriptConverter {
+    item_converter: TypstSectionConverter,
+}
+
+impl TypstManuscriptConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: TypstSectionConverter::new(options),
+        }
+    }
+}
+
+impl ManuscriptConverter for TypstManuscriptConverter {
+    type ItemConverter = TypstSectionConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(
+        &self,
+        sections: impl IntoIterator<Item = Section>,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        let mut sections = sections.into_iter().filter(is_published).peekable();
+        let title = match sections.peek() {
+            Some(Section::Title(title)) => TypstLineItemConverter::string(title),
+            _ => "none".to_string(),
+        };
+        writeln!(writer, "{}", TYPST_TEMPLATE)?;
+        writeln!(writer, "#show: manuscript.with(title: {})\n", title)?;
+        let mut previous_was_scene = false;
+        for section in sections {
+            info!("convert: {:?}", section);
+            let is_scene = matches!(section, Section::Scene(_, _));
+            if let (Section::Scene(_, _), true) = (&section, previous_was_scene) {
+                writeln!(
+                    writer,
+                    "#scene-break({})\n",
+                    TypstLineItemConverter::string(&self.options().scene_separator)
+                )?;
+            }
+            self.item_converter().write(section, writer)?;
+            previous_was_scene = is_scene;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::ManuscriptParser;
+
+    use super::*;
+
+    #[test]
+    fn test_convert() {
+        let input =
+            "# 表題\n\n```\n{吾輩|わがはい}は猫である。\n```\n```\n「名前は{まだ|.}無い」\n```\n";
+        let result =
+            TypstManuscriptConverter::default().convert(ManuscriptParser::new(input).collect());
+        let body = result.strip_prefix(TYPST_TEMPLATE).unwrap();
+        assert_eq!(
+            body,
+            "\n#show: manuscript.with(title: \"表題\")\n\n= 表題\n\n　#ruby(\"吾輩\", \"わがはい\")は猫である。\n\n#scene-break(\"†\")\n\n「名前は#kenten(\"まだ\")無い」\n\n"
+        );
+    }
+
+    #[test]
+    fn test_convert_without_title() {
+        let input = "```\n本文。\n```\n";
+        let result =
+            TypstManuscriptConverter::default().convert(ManuscriptParser::new(input).collect());
+        assert!(result.contains("#show: manuscript.with(title: none)\n"));
+    }
+}
@@ -0,0 +1,39 @@
+use std::io::{self, Write};
+
+use rstrial_parser::tokens::section::Section;
+
+use crate::converter::{ConverterOptions, LineConverter, SectionConverter};
+
+use super::{line_converter::TypstLineConverter, line_item_converter::TypstLineItemConverter};
+
+#[derive(Default)]
+pub struct TypstSectionConverter {
+    item_converter: TypstLineConverter,
+}
+
+impl TypstSectionConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: TypstLineConverter::new(options),
+        }
+    }
+}
+
+impl SectionConverter for TypstSectionConverter {
+    type ItemConverter = TypstLineConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(&self, section: Section, writer: &mut dyn Write) -> io::Result<()> {
+        match section {
+            Section::Title(title) => {
+                writeln!(writer, "= {}\n", TypstLineItemConverter::escape(&title))
+            }
+            Section::Scene(_, body) => body
+                .into_iter()
+                .try_for_each(|line| self.item_converter().write(line, writer)),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+// Template bundled by rstrial.
+// Typst does not support vertical writing yet, so manuscripts are set horizontally.
+#let manuscript(title: none, body) = {
+  set document(title: title) if title != none
+  set page(paper: "a5", margin: (x: 18mm, y: 20mm))
+  set text(lang: "ja", size: 10pt)
+  set par(justify: true, leading: 1em)
+  show heading: it => block(above: 2em, below: 1.5em, text(size: 1.4em, it.body))
+  body
+}
+
+// Ruby placed above the base text.
+#let ruby(base, reading) = box[#base#place(top + center, dy: -0.8em, text(size: 0.5em, reading))]
+
+// Emphasis dots on each character.
+#let kenten(body) = for c in body.clusters() { ruby(c, "﹅") }
+
+#let scene-break(mark) = align(center, block(above: 1.5em, below: 1.5em, mark))
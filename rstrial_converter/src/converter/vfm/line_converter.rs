@@ -1,13 +1,28 @@
-use crate::converter::LineConverter;
+use crate::converter::{ConverterOptions, LineConverter};
 
 use super::line_item_converter::VfmLineItemConverter;
 
-pub struct VfmLineConverter;
+#[derive(Default)]
+pub struct VfmLineConverter {
+    item_converter: VfmLineItemConverter,
+}
+
+impl VfmLineConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: VfmLineItemConverter::new(options),
+        }
+    }
+}
 
 impl LineConverter for VfmLineConverter {
     type ItemConverter = VfmLineItemConverter;
 
-    fn line_separator() -> String {
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn line_separator(&self) -> String {
         "\n\n".to_string()
     }
 }
@@ -30,7 +45,7 @@ mod tests {
             rstrial_parser::tokens::LineItem::Text("はまだ無い".to_string()),
             rstrial_parser::tokens::LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
         ]);
-        let result = VfmLineConverter::convert(line);
+        let result = VfmLineConverter::default().convert(line);
         assert_eq!(result, "　我が輩は、{名前|なまえ}はまだ無い。\n\n");
     }
 
@@ -47,7 +62,13 @@ mod tests {
             rstrial_parser::tokens::LineItem::Text("はまだ無い".to_string()),
             rstrial_parser::tokens::LineItem::EndOfSentence(Terminator::Normal("」".to_string())),
         ]);
-        let result = VfmLineConverter::convert(line);
+        let result = VfmLineConverter::default().convert(line);
         assert_eq!(result, " 「我が輩は、{名前|なまえ}はまだ無い」\n\n");
     }
+
+    #[test]
+    fn test_convert_comment() {
+        let line = Line::Comment("見当をつけるかどうか".to_string());
+        assert_eq!(VfmLineConverter::default().convert(line), "\n");
+    }
 }
@@ -1,9 +1,81 @@
-use crate::converter::ManuscriptConverter;
+use crate::converter::{ConverterOptions, ManuscriptConverter};
 
 use super::section_converter::VfmSectionConverter;
 
-pub struct VfmManuscriptConverter;
+#[derive(Default)]
+pub struct VfmManuscriptConverter {
+    item_converter: VfmSectionConverter,
+}
+
+impl VfmManuscriptConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: VfmSectionConverter::new(options),
+        }
+    }
+}
 
 impl ManuscriptConverter for VfmManuscriptConverter {
     type ItemConverter = VfmSectionConverter;
+
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstrial_parser::{ManuscriptParser, VfmParser};
+
+    use crate::converter::{
+        rstrial::manuscript_converter::RstrialManuscriptConverter, CommentPolicy, RubyPolicy,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_convert_and_import() {
+        let input = "# 表題\n\n```\n{吾輩|わがはい}は猫である。名前は{まだ|.}無い！\n\nにゃあ。\n```\n\n```\n「どうも{甘|うま}くかけないものだね」\n```\n";
+        let vfm = VfmManuscriptConverter::default().convert(ManuscriptParser::new(input).collect());
+        let result = RstrialManuscriptConverter::default().convert(VfmParser::new(&vfm).collect());
+        assert_eq!(result, input);
+    }
+
+    #[test]
+    fn test_convert_without_draft_and_skipped_scenes() {
+        let input = "```第１シーン {draft}\n下書き。\n```\n```第２シーン {.skip}\n没。\n```\n```第３シーン\n本文。\n```\n";
+        let result =
+            VfmManuscriptConverter::default().convert(ManuscriptParser::new(input).collect());
+        assert_eq!(result, "\n†\n\n　本文。\n\n");
+    }
+
+    #[test]
+    fn test_convert_with_options() {
+        let input = "```第１シーン\n{吾輩|わがはい}は猫である{#犬でも}。\n// メモ\n```\n```第２シーン\n「にゃあ」\n```\n";
+        let converter = VfmManuscriptConverter::new(ConverterOptions {
+            scene_separator: "＊".to_string(),
+            comments: CommentPolicy::Keep,
+            ruby: RubyPolicy::Parenthesize,
+            indent: "".to_string(),
+            ..Default::default()
+        });
+        let result = converter.convert(ManuscriptParser::new(input).collect());
+        assert_eq!(
+            result,
+            "\n＊\n\n吾輩（わがはい）は猫である<!--犬でも-->。\n\n<!-- メモ-->\n\n\n＊\n\n 「にゃあ」\n\n"
+        );
+    }
+
+    #[test]
+    fn test_write() {
+        let input = "# 表題\n\n```\n{吾輩|わがはい}は猫である。\n```\n```\nにゃあ。\n```\n";
+        let mut output = vec![];
+        VfmManuscriptConverter::default()
+            .write(ManuscriptParser::new(input), &mut output)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "# 表題\n\n\n　{吾輩|わがはい}は猫である。\n\n\n†\n\n　にゃあ。\n\n"
+        );
+    }
 }
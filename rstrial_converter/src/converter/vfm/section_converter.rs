@@ -1,22 +1,37 @@
+use std::io::{self, Write};
+
 use rstrial_parser::tokens::section::Section;
 
-use crate::converter::{LineConverter, SectionConverter};
+use crate::converter::{ConverterOptions, LineConverter, SectionConverter};
 
 use super::line_converter::VfmLineConverter;
 
-pub struct VfmSectionConverter;
+#[derive(Default)]
+pub struct VfmSectionConverter {
+    item_converter: VfmLineConverter,
+}
+
+impl VfmSectionConverter {
+    pub fn new(options: ConverterOptions) -> Self {
+        Self {
+            item_converter: VfmLineConverter::new(options),
+        }
+    }
+}
 
 impl SectionConverter for VfmSectionConverter {
     type ItemConverter = VfmLineConverter;
 
-    fn convert(section: Section) -> String {
+    fn item_converter(&self) -> &Self::ItemConverter {
+        &self.item_converter
+    }
+
+    fn write(&self, section: Section, writer: &mut dyn Write) -> io::Result<()> {
         match section {
-            Section::Title(title) => format!("# {}\n", title),
+            Section::Title(title) => writeln!(writer, "# {}", title),
             Section::Scene(_, body) => body
                 .into_iter()
-                .map(VfmLineConverter::convert)
-                .collect::<Vec<String>>()
-                .concat(),
+                .try_for_each(|line| self.item_converter().write(line, writer)),
         }
     }
 }
@@ -0,0 +1,85 @@
+use std::collections::HashSet;
+
+/// Escapes characters that have special meanings in XML text and attribute values.
+pub(crate) fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Formats a comment. A space is put after every `-`, so that neither `--` nor `-` right before
+/// `-->` is left, which are not allowed inside XML comments.
+pub(crate) fn comment(text: &str) -> String {
+    format!("<!--{}-->", text.replace('-', "- "))
+}
+
+/// Makes a valid XML ID (NCName) from a scene ID. Characters not allowed in names become `-`,
+/// and `s-` is put before an ID that does not start with a letter or `_`.
+pub(crate) fn id(text: &str) -> String {
+    let id: String = text
+        .chars()
+        .map(|c| match c {
+            c if c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | '・') => c,
+            _ => '-',
+        })
+        .collect();
+    match id.chars().next() {
+        Some(c) if c.is_alphabetic() || c == '_' => id,
+        _ => format!("s-{}", id),
+    }
+}
+
+/// IDs already used in a document, to keep IDs of scenes with the same title unique.
+#[derive(Default)]
+pub(crate) struct Ids {
+    used: HashSet<String>,
+}
+
+impl Ids {
+    /// Returns a valid ID from `text`, with a counter appended if it is already used.
+    pub(crate) fn unique(&mut self, text: &str) -> String {
+        let base = id(text);
+        let mut id = base.clone();
+        let mut count = 1;
+        while self.used.contains(&id) {
+            count += 1;
+            id = format!("{}-{}", base, count);
+        }
+        self.used.insert(id.clone());
+        id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("<猫 & \"犬\">"), "&lt;猫 &amp; &quot;犬&quot;&gt;");
+        assert_eq!(comment("a--b"), "<!--a- - b-->");
+        assert_eq!(comment("a---b"), "<!--a- - - b-->");
+        assert_eq!(comment("a-"), "<!--a- -->");
+    }
+
+    #[test]
+    fn test_id() {
+        assert_eq!(id("第１シーン"), "第１シーン");
+        assert_eq!(id("1章 (前編)"), "s-1章--前編-");
+        assert_eq!(id("-a"), "s--a");
+        let mut ids = Ids::default();
+        assert_eq!(ids.unique("幕間"), "幕間");
+        assert_eq!(ids.unique("幕間-2"), "幕間-2");
+        assert_eq!(ids.unique("幕間"), "幕間-3");
+        assert_eq!(ids.unique("a:b"), "a-b");
+    }
+}
@@ -1,8 +1,15 @@
 pub mod entities;
 pub mod parser;
 pub mod tokens;
+pub mod visit;
 
+pub use parser::aozora_parser::AozoraParser;
+pub use parser::asciidoc_parser::AsciidocParser;
+pub use parser::line_item_builder::is_kanji;
 pub use parser::manuscript_parser::ManuscriptParser;
+pub use parser::section_parser::SectionParser;
+pub use parser::vfm_parser::VfmParser;
+pub use parser::web_novel_parser::{Platform, WebNovelParser, NAROU_MAX_RUBY_LENGTH};
 
 pub fn add(left: usize, right: usize) -> usize {
     left + right
@@ -1,4 +1,10 @@
+pub(crate) mod aozora_parser;
+pub(crate) mod asciidoc_parser;
+pub(crate) mod info_string_parser;
+pub(crate) mod line_item_builder;
 pub(crate) mod line_item_parser;
 pub(crate) mod manuscript_parser;
 pub(crate) mod section_parser;
 pub(crate) mod terminator_parser;
+pub(crate) mod vfm_parser;
+pub(crate) mod web_novel_parser;
@@ -0,0 +1,332 @@
+use std::{collections::VecDeque, iter::Peekable, str::Lines};
+
+use log::trace;
+use logos::Logos;
+
+use crate::tokens::{
+    section::{Document, Section},
+    Line, LineItem,
+};
+
+use super::line_item_builder::LineItemBuilder;
+
+// Tokens for a line written in Aozora Bunko notation.
+#[derive(Logos, Debug, PartialEq, Clone)]
+enum AozoraToken {
+    // Plaintext to be rendered as-is.
+    #[regex(r"[^|｜《［,、，!?！？。」]+", priority = 0, callback = |lex| lex.slice().to_owned())]
+    Text(String),
+    // A ruby marker which is not a part of any notation.
+    #[regex(r"[|｜《［]", priority = 0, callback = |lex| lex.slice().to_owned())]
+    Stray(String),
+    // Ruby with an explicit base text such as `|漢字《かんじ》`.
+    #[regex(r"[|｜][^|｜《》]+《[^》]+》", AozoraParser::to_ruby)]
+    RubyWithBase((String, String)),
+    // Ruby for the preceding run of kanji such as `漢字《かんじ》`.
+    #[regex(r"《[^》]+》", |lex| lex.slice().trim_start_matches('《').trim_end_matches('》').to_owned())]
+    Ruby(String),
+    // An annotation such as `［＃改ページ］`.
+    #[regex(r"［＃[^］]*］", |lex| lex.slice().trim_start_matches("［＃").trim_end_matches('］').to_owned())]
+    Annotation(String),
+    // A Sentence delimiter such as `,` or `、`.
+    #[regex(r"[,、，]", |lex| lex.slice().to_owned())]
+    Comma(String),
+    // End of sentence.
+    #[regex(r"[!?！？。」]+", |lex| lex.slice().to_owned())]
+    EndOfSentence(String),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Annotation {
+    // `［＃「X」は大見出し］` starts a new title.
+    Title(String),
+    // `［＃「X」は中見出し］` and `［＃「X」は小見出し］` start a new scene.
+    SceneTitle(String),
+    // `［＃「X」に傍点］` marks the preceding `X` with sesame.
+    Sesame(String),
+    // `［＃改ページ］` and its variants separate scenes.
+    PageBreak,
+    // Any other annotation is kept as a comment.
+    Other(String),
+}
+
+impl Annotation {
+    fn parse(annotation: &str) -> Self {
+        let target = annotation
+            .strip_prefix('「')
+            .and_then(|rest| rest.split_once('」'));
+        match target {
+            Some((target, "は大見出し")) => Annotation::Title(target.to_string()),
+            Some((target, "は中見出し" | "は小見出し")) => {
+                Annotation::SceneTitle(target.to_string())
+            }
+            Some((target, kind)) if kind.starts_with('に') && kind.ends_with("傍点") => {
+                Annotation::Sesame(target.to_string())
+            }
+            _ => match annotation {
+                "改ページ" | "改丁" | "改見開き" | "改段" => Annotation::PageBreak,
+                _ => Annotation::Other(annotation.to_string()),
+            },
+        }
+    }
+}
+
+/// Parser for texts written in Aozora Bunko notation.
+///
+/// The first line is treated as the title and the following lines up to the first blank line
+/// (e.g. the author name) are skipped. Scenes are separated by `†`, page breaks and
+/// middle/small headings, and the text after `底本：` is ignored.
+#[derive(Debug)]
+pub struct AozoraParser<'a> {
+    lines: Peekable<Lines<'a>>,
+    started: bool,
+    finished: bool,
+    scene_title: String,
+    text_buffer: Vec<&'a str>,
+    sections: VecDeque<Section>,
+}
+
+impl<'a> AozoraParser<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            lines: source.lines().peekable(),
+            started: false,
+            finished: false,
+            scene_title: String::new(),
+            text_buffer: vec![],
+            sections: VecDeque::new(),
+        }
+    }
+
+    fn to_ruby(lex: &logos::Lexer<AozoraToken>) -> Option<(String, String)> {
+        lex.slice()
+            .trim_start_matches(['|', '｜'])
+            .strip_suffix('》')?
+            .split_once('《')
+            .map(|(base, ruby)| (base.to_string(), ruby.to_string()))
+    }
+
+    fn parse_front_matter(&mut self) {
+        self.started = true;
+        match self.lines.peek() {
+            Some(line) if !line.trim().is_empty() => {
+                let title = line.trim().to_string();
+                self.sections.push_back(Section::Title(title));
+                while let Some(line) = self.lines.next_if(|line| !line.trim().is_empty()) {
+                    trace!("aozora: skip front matter {:?}", line);
+                }
+            }
+            _ => {}
+        }
+        while self.lines.next_if(|line| line.trim().is_empty()).is_some() {}
+        // Skip the legend of symbols enclosed by dashed lines.
+        if self
+            .lines
+            .next_if(|line| line.starts_with("-----"))
+            .is_some()
+        {
+            for line in self.lines.by_ref() {
+                if line.starts_with("-----") {
+                    break;
+                }
+            }
+        }
+    }
+
+    fn flush_scene(&mut self) {
+        let text_buffer = std::mem::take(&mut self.text_buffer);
+        let title = std::mem::take(&mut self.scene_title);
+        let start = text_buffer.iter().position(|line| !line.trim().is_empty());
+        let end = text_buffer.iter().rposition(|line| !line.trim().is_empty());
+        let body: Vec<Line> = match (start, end) {
+            (Some(start), Some(end)) => text_buffer[start..=end]
+                .iter()
+                .map(|line| Self::parse_line(line))
+                .collect(),
+            _ => vec![],
+        };
+        if !body.is_empty() || !title.is_empty() {
+            self.sections
+                .push_back(Section::Scene(Document::new(title, None, vec![]), body));
+        }
+    }
+
+    fn parse_line(line: &str) -> Line {
+        let line = line.trim_start_matches([' ', '　']);
+        let items = Self::parse_items(line);
+        match line.starts_with('「') {
+            true => Line::Conversation(items),
+            false => Line::Paragraph(items),
+        }
+    }
+
+    fn parse_items(line: &str) -> Vec<LineItem> {
+        let mut builder = LineItemBuilder::new();
+        for token in AozoraToken::lexer(line) {
+            let token = token.unwrap_or_else(|_| panic!("parsing failed: {}", line));
+            match token {
+                AozoraToken::Text(text) | AozoraToken::Stray(text) => builder.push_text(text),
+                AozoraToken::RubyWithBase((base, ruby)) => builder.push_ruby(base, ruby),
+                AozoraToken::Ruby(ruby) => {
+                    if !builder.push_kanji_ruby(ruby.clone()) {
+                        builder.push_text(format!("《{}》", ruby));
+                    }
+                }
+                AozoraToken::Annotation(annotation) => match Annotation::parse(&annotation) {
+                    Annotation::Sesame(target) => {
+                        if !builder.push_sesame(&target) {
+                            builder.push(LineItem::Comment(annotation));
+                        }
+                    }
+                    Annotation::Other(annotation) => builder.push(LineItem::Comment(annotation)),
+                    Annotation::Title(_) | Annotation::SceneTitle(_) | Annotation::PageBreak => {}
+                },
+                AozoraToken::Comma(comma) => builder.push(LineItem::Comma(comma)),
+                AozoraToken::EndOfSentence(terminator) => builder.push_terminator(&terminator),
+            }
+        }
+        builder.build()
+    }
+
+    fn annotations(line: &str) -> Vec<Annotation> {
+        AozoraToken::lexer(line)
+            .filter_map(|token| match token {
+                Ok(AozoraToken::Annotation(annotation)) => Some(Annotation::parse(&annotation)),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl<'a> Iterator for AozoraParser<'a> {
+    type Item = Section;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.parse_front_matter();
+        }
+        while self.sections.is_empty() && !self.finished {
+            let Some(line) = self.lines.next() else {
+                self.finished = true;
+                self.flush_scene();
+                break;
+            };
+            trace!("aozora: {:?}", line);
+            if line.starts_with("底本：") {
+                self.finished = true;
+                self.flush_scene();
+                break;
+            }
+            if line.trim() == "†" {
+                self.flush_scene();
+                continue;
+            }
+            let annotations = Self::annotations(line);
+            let heading = annotations.iter().find_map(|annotation| match annotation {
+                Annotation::Title(_) | Annotation::SceneTitle(_) => Some(annotation.clone()),
+                _ => None,
+            });
+            match heading {
+                Some(Annotation::Title(title)) => {
+                    self.flush_scene();
+                    self.sections.push_back(Section::Title(title));
+                }
+                Some(Annotation::SceneTitle(title)) => {
+                    self.flush_scene();
+                    self.scene_title = title;
+                }
+                _ if annotations.contains(&Annotation::PageBreak) => self.flush_scene(),
+                _ => self.text_buffer.push(line),
+            }
+        }
+        let section = self.sections.pop_front();
+        trace!("parse: {:?}", section);
+        section
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tokens::line_item::Terminator;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let input = "吾輩は猫である\n夏目漱石\n\n-------------------------------------------------------\n【テキスト中に現れる記号について】\n《》：ルビ\n-------------------------------------------------------\n\n［＃８字下げ］一［＃「一」は中見出し］\n\n　吾輩《わがはい》は猫である。名前はまだ無い。\n「にゃあ！　にゃあ」\n［＃改ページ］\n　|吾輩は猫《I am a cat》。名前は|まだ《・・・・・・》無い！　\n　見当がつかぬ［＃「つかぬ」に傍点］。\n\n底本：「吾輩は猫である」岩波文庫\n";
+        let actual = AozoraParser::new(input).collect::<Vec<Section>>();
+        let expected = vec![
+            Section::Title("吾輩は猫である".to_string()),
+            Section::Scene(
+                Document::new("一".to_string(), None, vec![]),
+                vec![
+                    Line::Paragraph(vec![
+                        LineItem::TextWithRuby(("吾輩".to_string(), "わがはい".to_string())),
+                        LineItem::Text("は猫である".to_string()),
+                        LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+                        LineItem::Text("名前はまだ無い".to_string()),
+                        LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+                    ]),
+                    Line::Conversation(vec![
+                        LineItem::Text("「にゃあ".to_string()),
+                        LineItem::EndOfSentence(Terminator::Exclamation("！".to_string())),
+                        LineItem::Text("にゃあ".to_string()),
+                        LineItem::EndOfSentence(Terminator::Normal("」".to_string())),
+                    ]),
+                ],
+            ),
+            Section::Scene(
+                Document::new("".to_string(), None, vec![]),
+                vec![
+                    Line::Paragraph(vec![
+                        LineItem::TextWithRuby(("吾輩は猫".to_string(), "I am a cat".to_string())),
+                        LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+                        LineItem::Text("名前は".to_string()),
+                        LineItem::TextWithSesame(("まだ".to_string(), '・')),
+                        LineItem::Text("無い".to_string()),
+                        LineItem::EndOfSentence(Terminator::Exclamation("！".to_string())),
+                    ]),
+                    Line::Paragraph(vec![
+                        LineItem::Text("見当が".to_string()),
+                        LineItem::TextWithSesame(("つかぬ".to_string(), '・')),
+                        LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+                    ]),
+                ],
+            ),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_scene_separator() {
+        let input = "\n\n　にゃあ。\n\n†\n\n　わん。\n";
+        let actual = AozoraParser::new(input).collect::<Vec<Section>>();
+        let expected = vec![
+            Section::Scene(
+                Document::new("".to_string(), None, vec![]),
+                vec![Line::Paragraph(vec![
+                    LineItem::Text("にゃあ".to_string()),
+                    LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+                ])],
+            ),
+            Section::Scene(
+                Document::new("".to_string(), None, vec![]),
+                vec![Line::Paragraph(vec![
+                    LineItem::Text("わん".to_string()),
+                    LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+                ])],
+            ),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_annotation_as_comment() {
+        let actual = AozoraParser::parse_items("［＃３字下げ］ルビのない《かな》");
+        let expected = vec![
+            LineItem::Comment("３字下げ".to_string()),
+            LineItem::Text("ルビのない《かな》".to_string()),
+        ];
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,415 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    iter::Peekable,
+    str::Lines,
+};
+
+use log::trace;
+
+use crate::tokens::{
+    section::{Document, Section},
+    Line, LineItem,
+};
+
+use super::{line_item_builder::SESAME_MARKS, line_item_parser::LineItemParser};
+
+/// Parser for AsciiDoc manuscripts such as the output of `AsciidocManuscriptConverter`.
+///
+/// `= ` headings become titles and lower level sections become scenes, taking their IDs from
+/// `[#id]` or `[[id]]` anchors. Scenes are also separated by thematic breaks, page breaks and
+/// the scene separator, which is `†` unless set by `with_scene_separator`, in a paragraph marked
+/// with `[.text-center]`. Other centered paragraphs are read as they are. Ruby is read from `+++<ruby>` passthroughs as well as
+/// the rstrial notation, and `[.sesame]#text#` becomes sesame. Attribute references such as
+/// `{author}` are resolved by the attribute entries of the document and the built-in character
+/// replacements, and unknown ones are left as text.
+#[derive(Debug)]
+pub struct AsciidocParser<'a> {
+    lines: Peekable<Lines<'a>>,
+    finished: bool,
+    scene_title: String,
+    scene_id: Option<String>,
+    pending_id: Option<String>,
+    scene_separator: String,
+    // Whether the next paragraph is marked with `[.text-center]`.
+    centered_next: bool,
+    paragraph_buffer: Vec<&'a str>,
+    body_buffer: Vec<Line>,
+    sections: VecDeque<Section>,
+    attributes: BTreeMap<String, String>,
+}
+
+impl<'a> AsciidocParser<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            lines: source.lines().peekable(),
+            finished: false,
+            scene_title: String::new(),
+            scene_id: None,
+            pending_id: None,
+            scene_separator: "†".to_string(),
+            centered_next: false,
+            paragraph_buffer: vec![],
+            body_buffer: vec![],
+            sections: VecDeque::new(),
+            attributes: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the scene separator written in centered paragraphs, such as `--scene-separator` of the converters.
+    pub fn with_scene_separator(mut self, scene_separator: &str) -> Self {
+        self.scene_separator = scene_separator.trim().to_string();
+        self
+    }
+
+    fn flush_paragraph(&mut self) {
+        if self.paragraph_buffer.is_empty() {
+            return;
+        }
+        let paragraph = std::mem::take(&mut self.paragraph_buffer).concat();
+        self.body_buffer.push(self.parse_paragraph(&paragraph));
+    }
+
+    fn flush_scene(&mut self) {
+        self.flush_paragraph();
+        let body = std::mem::take(&mut self.body_buffer);
+        let title = std::mem::take(&mut self.scene_title);
+        let id = self.scene_id.take();
+        if !body.is_empty() || !title.is_empty() {
+            let mut document = Document::new(title, None, vec![]);
+            document.id = id;
+            self.sections.push_back(Section::Scene(document, body));
+        }
+    }
+
+    fn block_comment(&mut self) -> String {
+        let mut comment = vec![];
+        for line in self.lines.by_ref() {
+            if line.trim_end() == "////" {
+                break;
+            }
+            comment.push(line);
+        }
+        comment.join("\n")
+    }
+
+    fn parse_paragraph(&self, paragraph: &str) -> Line {
+        if paragraph.trim() == "{nbsp}" {
+            return Line::Paragraph(vec![]);
+        }
+        if let Some(quotation) = paragraph.strip_prefix('>') {
+            return Line::Quotation(self.parse_items(quotation.trim_matches([' ', '　'])));
+        }
+        // The space after a line-final exclamation is added by converters.
+        let paragraph = paragraph.trim_matches([' ', '　']);
+        let items = self.parse_items(paragraph);
+        match paragraph.starts_with('「') {
+            true => Line::Conversation(items),
+            false => Line::Paragraph(items),
+        }
+    }
+
+    fn parse_items(&self, paragraph: &str) -> Vec<LineItem> {
+        let paragraph = self.rewrite_inline(paragraph);
+        LineItemParser::parse_lossy(&paragraph)
+            .into_iter()
+            .map(|item| match item {
+                LineItem::TextWithRuby((text, ruby))
+                    if ruby.chars().all(|c| SESAME_MARKS.contains(&c)) =>
+                {
+                    LineItem::TextWithSesame((text, '・'))
+                }
+                item => item,
+            })
+            .collect()
+    }
+
+    // Rewrites passthroughs and roles into the rstrial notation before lexing.
+    fn rewrite_inline(&self, paragraph: &str) -> String {
+        let mut rewritten = String::with_capacity(paragraph.len());
+        let mut rest = paragraph;
+        while !rest.is_empty() {
+            if let Some((passthrough, after)) = rest
+                .strip_prefix("+++")
+                .and_then(|rest| rest.split_once("+++"))
+            {
+                if let Some(ruby) = passthrough
+                    .strip_prefix("<ruby>")
+                    .and_then(|ruby| ruby.strip_suffix("</rt></ruby>"))
+                {
+                    if let Some((base, reading)) = ruby.split_once("<rt>") {
+                        rewritten.push_str(&format!(
+                            "{{{}|{}}}",
+                            Self::unescape(base),
+                            Self::unescape(reading)
+                        ));
+                        rest = after;
+                        continue;
+                    }
+                }
+                if let Some(comment) = passthrough
+                    .strip_prefix("<!--")
+                    .and_then(|comment| comment.strip_suffix("-->"))
+                {
+                    rewritten.push_str(&format!("{{#{}}}", comment));
+                    rest = after;
+                    continue;
+                }
+            }
+            if let Some((text, after)) = rest
+                .strip_prefix("[.sesame]#")
+                .and_then(|rest| rest.split_once('#'))
+            {
+                rewritten.push_str(&format!("{{{}|.}}", text));
+                rest = after;
+                continue;
+            }
+            if let Some((value, after)) = rest
+                .strip_prefix('{')
+                .and_then(|rest| rest.split_once('}'))
+                .and_then(|(name, after)| Some((self.attribute(name)?, after)))
+            {
+                rewritten.push_str(&value);
+                rest = after;
+                continue;
+            }
+            let mut chars = rest.chars();
+            rewritten.extend(chars.next());
+            rest = chars.as_str();
+        }
+        rewritten
+    }
+
+    // Value of an attribute reference: an attribute entry of the document or a built-in character.
+    fn attribute(&self, name: &str) -> Option<String> {
+        if let Some(value) = self.attributes.get(name) {
+            return Some(value.clone());
+        }
+        let value = match name {
+            "nbsp" => "\u{a0}",
+            "sp" => " ",
+            "empty" => "",
+            "zwsp" => "\u{200b}",
+            "wj" => "\u{2060}",
+            "amp" => "&",
+            "lt" => "<",
+            "gt" => ">",
+            "quot" => "\"",
+            "apos" => "'",
+            "startsb" => "[",
+            "endsb" => "]",
+            "vbar" => "|",
+            "caret" => "^",
+            "asterisk" => "*",
+            "tilde" => "~",
+            "plus" => "+",
+            "backslash" => "\\",
+            "backtick" => "`",
+            "two-colons" => "::",
+            "two-semicolons" => ";;",
+            "deg" => "°",
+            "brvbar" => "¦",
+            _ => return None,
+        };
+        Some(value.to_string())
+    }
+
+    fn unescape(text: &str) -> String {
+        text.replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&apos;", "'")
+            .replace("&amp;", "&")
+    }
+
+    fn anchor(line: &str) -> Option<String> {
+        let line = line.trim_end();
+        line.strip_prefix("[[")
+            .and_then(|id| id.strip_suffix("]]"))
+            .or_else(|| line.strip_prefix("[#").and_then(|id| id.strip_suffix(']')))
+            .map(|id| id.split([',', '.']).next().unwrap_or(id).to_string())
+            .filter(|id| !id.is_empty())
+    }
+
+    fn is_scene_separator(line: &str) -> bool {
+        let line: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+        line == "†"
+            || line == "'''"
+            || line == "<<<"
+            || (line.len() >= 3
+                && ['-', '*', '_']
+                    .iter()
+                    .any(|mark| line.chars().all(|c| c == *mark)))
+    }
+
+    // Name and value of an attribute entry such as `:author: 夏目漱石`.
+    fn attribute_entry(line: &str) -> Option<(&str, &str)> {
+        line.strip_prefix(':')
+            .and_then(|line| line.split_once(':'))
+            .filter(|(name, _)| {
+                !name.is_empty()
+                    && name
+                        .chars()
+                        .all(|c| c.is_alphanumeric() || c == '-' || c == '_' || c == '!')
+            })
+            .map(|(name, value)| (name, value.trim()))
+    }
+}
+
+impl<'a> Iterator for AsciidocParser<'a> {
+    type Item = Section;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.sections.is_empty() && !self.finished {
+            let Some(line) = self.lines.next() else {
+                self.finished = true;
+                self.flush_scene();
+                break;
+            };
+            trace!("asciidoc: {:?}", line);
+            if self.centered_next && !line.trim().is_empty() {
+                self.centered_next = false;
+                if line.trim() == self.scene_separator {
+                    self.flush_scene();
+                    continue;
+                }
+            }
+            match line {
+                line if line.starts_with("= ") => {
+                    self.flush_scene();
+                    self.pending_id = None;
+                    let title = line.strip_prefix("= ").unwrap().trim().to_string();
+                    self.sections.push_back(Section::Title(title));
+                }
+                line if line.starts_with("==") && line.trim_start_matches('=').starts_with(' ') => {
+                    self.flush_scene();
+                    self.scene_title = line.trim_start_matches('=').trim().to_string();
+                    self.scene_id = self.pending_id.take();
+                }
+                line if line.trim_end() == "////" => {
+                    self.flush_paragraph();
+                    let comment = self.block_comment();
+                    self.body_buffer.push(Line::BlockComment(comment));
+                }
+                line if line.starts_with("//") && !line.starts_with("///") => {
+                    self.flush_paragraph();
+                    let comment = line.strip_prefix("//").unwrap().to_string();
+                    self.body_buffer.push(Line::Comment(comment));
+                }
+                line if self.paragraph_buffer.is_empty() && Self::anchor(line).is_some() => {
+                    self.pending_id = Self::anchor(line);
+                }
+                line if self.paragraph_buffer.is_empty() && line.trim_end() == "[.text-center]" => {
+                    self.centered_next = true;
+                }
+                line if self.paragraph_buffer.is_empty()
+                    && Self::attribute_entry(line).is_some() =>
+                {
+                    let (name, value) = Self::attribute_entry(line).unwrap();
+                    match name.strip_suffix('!').or_else(|| name.strip_prefix('!')) {
+                        Some(name) => self.attributes.remove(name),
+                        None => self.attributes.insert(name.to_string(), value.to_string()),
+                    };
+                }
+                line if Self::is_scene_separator(line) => self.flush_scene(),
+                line if line.trim_matches([' ', '\t']).is_empty() => self.flush_paragraph(),
+                _ => self.paragraph_buffer.push(line),
+            }
+        }
+        let section = self.sections.pop_front();
+        trace!("parse: {:?}", section);
+        section
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tokens::line_item::Terminator;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let input = "= 吾輩は猫である\n:lang: ja\n:doctype: book\n\n[#first]\n== 第1シーン\n\n　+++<ruby>吾輩<rt>わがはい</rt></ruby>+++は猫である。\n名前は[.sesame]#まだ#無い。\n\n// 猫でなく犬にすることも検討\n\n{nbsp}\n\n[.text-center]\n†\n\n> 「どうも」\n\n「{甘|うま}い」\n\n////\n没にした書き出し\n////\n";
+        let actual = AsciidocParser::new(input).collect::<Vec<Section>>();
+        let mut first = Document::new("第1シーン".to_string(), None, vec![]);
+        first.id = Some("first".to_string());
+        let expected = vec![
+            Section::Title("吾輩は猫である".to_string()),
+            Section::Scene(
+                first,
+                vec![
+                    Line::Paragraph(vec![
+                        LineItem::TextWithRuby(("吾輩".to_string(), "わがはい".to_string())),
+                        LineItem::Text("は猫である".to_string()),
+                        LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+                        LineItem::Text("名前は".to_string()),
+                        LineItem::TextWithSesame(("まだ".to_string(), '・')),
+                        LineItem::Text("無い".to_string()),
+                        LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+                    ]),
+                    Line::Comment(" 猫でなく犬にすることも検討".to_string()),
+                    Line::Paragraph(vec![]),
+                ],
+            ),
+            Section::Scene(
+                Document::new("".to_string(), None, vec![]),
+                vec![
+                    Line::Quotation(vec![
+                        LineItem::Text("「どうも".to_string()),
+                        LineItem::EndOfSentence(Terminator::Normal("」".to_string())),
+                    ]),
+                    Line::Conversation(vec![
+                        LineItem::Text("「".to_string()),
+                        LineItem::TextWithRuby(("甘".to_string(), "うま".to_string())),
+                        LineItem::Text("い".to_string()),
+                        LineItem::EndOfSentence(Terminator::Normal("」".to_string())),
+                    ]),
+                    Line::BlockComment("没にした書き出し".to_string()),
+                ],
+            ),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_attribute_references() {
+        let input = ":author: 夏目漱石\n\n== 第1シーン\n\n{author}作{nbsp}{unknown}の猫 } 犬。\n";
+        let actual = AsciidocParser::new(input).collect::<Vec<Section>>();
+        let expected = vec![Section::Scene(
+            Document::new("第1シーン".to_string(), None, vec![]),
+            vec![Line::Paragraph(vec![
+                LineItem::Text("夏目漱石作\u{a0}{unknown}の猫 } 犬".to_string()),
+                LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+            ])],
+        )];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_centered_paragraph() {
+        let input = "== 場面\n\n本文。\n\n[.text-center]\n＊\n\n続き。\n\n[.text-center]\n――完――\n";
+        let actual = AsciidocParser::new(input)
+            .with_scene_separator("＊")
+            .collect::<Vec<Section>>();
+        let expected = vec![
+            Section::Scene(
+                Document::new("場面".to_string(), None, vec![]),
+                vec![Line::Paragraph(vec![
+                    LineItem::Text("本文".to_string()),
+                    LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+                ])],
+            ),
+            Section::Scene(
+                Document::new("".to_string(), None, vec![]),
+                vec![
+                    Line::Paragraph(vec![
+                        LineItem::Text("続き".to_string()),
+                        LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+                    ]),
+                    Line::Paragraph(vec![LineItem::Text("――完――".to_string())]),
+                ],
+            ),
+        ];
+        assert_eq!(actual, expected);
+    }
+}
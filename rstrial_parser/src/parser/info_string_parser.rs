@@ -0,0 +1,114 @@
+use crate::tokens::section::Document;
+
+/// Parser for the info string of a scene block such as `第二シーン {#scene-2 pov=美咲 draft}`.
+///
+/// The trailing `{...}` is an attribute list of `#id`, `key=value` (or `key="quoted value"`)
+/// and flags such as `draft`. `.flag` is accepted as a flag too. Everything before it is the title.
+pub struct InfoStringParser {
+    pub source: String,
+}
+
+impl InfoStringParser {
+    pub fn new(text: &str) -> Self {
+        Self {
+            source: text.to_string(),
+        }
+    }
+
+    pub fn parse(&self) -> Document {
+        let (title, attributes) = self.split();
+        let mut document = Document::new(title.trim().to_string(), None, vec![]);
+        if let Some(attributes) = attributes {
+            for attribute in Self::tokenize(attributes) {
+                match attribute.split_once('=') {
+                    Some((key, value)) => {
+                        document
+                            .attributes
+                            .insert(key.to_string(), value.trim_matches('"').to_string());
+                    }
+                    None => match attribute.strip_prefix('#') {
+                        Some(id) => document.id = Some(id.to_string()),
+                        None => document
+                            .flags
+                            .push(attribute.trim_start_matches('.').to_string()),
+                    },
+                }
+            }
+        }
+        document
+    }
+
+    // Splits the source into the title and the attribute list. Braces including `|` are ruby.
+    fn split(&self) -> (&str, Option<&str>) {
+        let source = self.source.trim_end();
+        let attributes = source.strip_suffix('}').and_then(|rest| {
+            let (title, attributes) = rest.rsplit_once('{')?;
+            (!attributes.contains('|')).then_some((title, attributes))
+        });
+        match attributes {
+            Some((title, attributes)) => (title, Some(attributes)),
+            None => (source, None),
+        }
+    }
+
+    // Splits attributes by whitespaces except ones in double quotes.
+    fn tokenize(attributes: &str) -> Vec<String> {
+        let mut tokens = vec![];
+        let mut token = String::new();
+        let mut quoted = false;
+        for c in attributes.chars() {
+            match c {
+                '"' => {
+                    quoted = !quoted;
+                    token.push(c);
+                }
+                c if c.is_whitespace() && !quoted => {
+                    if !token.is_empty() {
+                        tokens.push(std::mem::take(&mut token));
+                    }
+                }
+                c => token.push(c),
+            }
+        }
+        if !token.is_empty() {
+            tokens.push(token);
+        }
+        tokens
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let parser = InfoStringParser::new(
+            " 第二シーン {#scene-2 pov=美咲 place=\"駅の ホーム\" draft .skip}",
+        );
+        let document = parser.parse();
+        assert_eq!(document.title, "第二シーン");
+        assert_eq!(document.id, Some("scene-2".to_string()));
+        assert_eq!(document.attributes.get("pov"), Some(&"美咲".to_string()));
+        assert_eq!(
+            document.attributes.get("place"),
+            Some(&"駅の ホーム".to_string())
+        );
+        assert_eq!(
+            document.flags,
+            vec!["draft".to_string(), "skip".to_string()]
+        );
+        assert_eq!(
+            document.attribute_list(),
+            "{#scene-2 place=\"駅の ホーム\" pov=美咲 draft skip}"
+        );
+    }
+
+    #[test]
+    fn test_parse_title_only() {
+        let document = InfoStringParser::new("{吾輩|わがはい}の章").parse();
+        assert_eq!(document.title, "{吾輩|わがはい}の章");
+        assert_eq!(document.id, None);
+        assert_eq!(document.attribute_list(), "");
+    }
+}
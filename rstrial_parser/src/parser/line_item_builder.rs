@@ -0,0 +1,168 @@
+use crate::tokens::{line_item::Terminator, LineItem};
+
+use super::terminator_parser::TerminatorParser;
+
+// Characters used as ruby to express sesame(boten) in Japanese texts.
+pub(crate) const SESAME_MARKS: [char; 3] = ['・', '﹅', '﹆'];
+
+/// Returns whether `c` is a kanji, to which ruby may be attached without `|` on web novel platforms.
+pub fn is_kanji(c: char) -> bool {
+    matches!(c, '\u{4E00}'..='\u{9FFF}' | '\u{3400}'..='\u{4DBF}' | '々' | '〆' | 'ヶ' | '〇')
+}
+
+/// Builder of `LineItem`s for notations which attach ruby or sesame to the preceding text,
+/// such as `漢字《かんじ》` in Aozora Bunko and web novel platforms.
+#[derive(Debug, Default)]
+pub(crate) struct LineItemBuilder {
+    items: Vec<LineItem>,
+}
+
+impl LineItemBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, item: LineItem) {
+        self.items.push(item);
+    }
+
+    pub fn push_text(&mut self, text: String) {
+        // A full-width space after exclamations is a typesetting convention, not a part of the text.
+        let text = match self.items.last() {
+            Some(LineItem::EndOfSentence(Terminator::Exclamation(_))) => text
+                .strip_prefix('　')
+                .map(|text| text.to_string())
+                .unwrap_or(text),
+            _ => text,
+        };
+        if text.is_empty() {
+            return;
+        }
+        match self.items.last_mut() {
+            Some(LineItem::Text(previous)) => previous.push_str(&text),
+            _ => self.items.push(LineItem::Text(text)),
+        }
+    }
+
+    pub fn push_terminator(&mut self, terminator: &str) {
+        let parser = TerminatorParser::new(terminator);
+        self.items.push(LineItem::EndOfSentence(parser.parse()));
+    }
+
+    /// Pushes ruby with an explicit base text. Ruby consisting of sesame marks becomes sesame,
+    /// which is merged into the preceding sesame such as `|ま《・》|だ《・》`.
+    pub fn push_ruby(&mut self, base: String, ruby: String) {
+        match (
+            ruby.chars().all(|c| SESAME_MARKS.contains(&c)),
+            self.items.last_mut(),
+        ) {
+            (true, Some(LineItem::TextWithSesame((previous, _)))) => previous.push_str(&base),
+            (true, _) => self.items.push(LineItem::TextWithSesame((base, '・'))),
+            (false, _) => self.items.push(LineItem::TextWithRuby((base, ruby))),
+        }
+    }
+
+    /// Attaches ruby to the run of kanji at the end of the preceding text.
+    /// Returns `false` if the preceding text does not end with kanji.
+    pub fn push_kanji_ruby(&mut self, ruby: String) -> bool {
+        let base = self.split_last_text(|text| {
+            text.char_indices()
+                .rev()
+                .take_while(|(_, c)| is_kanji(*c))
+                .last()
+                .map(|(index, _)| index)
+        });
+        match base {
+            Some(base) => {
+                self.push_ruby(base, ruby);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Marks `target` at the end of the preceding text with sesame.
+    /// Returns `false` if the preceding text does not end with `target`.
+    pub fn push_sesame(&mut self, target: &str) -> bool {
+        let base =
+            self.split_last_text(|text| text.ends_with(target).then(|| text.len() - target.len()));
+        match base {
+            Some(base) => {
+                self.items.push(LineItem::TextWithSesame((base, '・')));
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn build(self) -> Vec<LineItem> {
+        self.items
+    }
+
+    fn split_last_text(&mut self, position: impl Fn(&str) -> Option<usize>) -> Option<String> {
+        let Some(LineItem::Text(text)) = self.items.last_mut() else {
+            return None;
+        };
+        let index = position(text).filter(|index| *index < text.len())?;
+        let base = text.split_off(index);
+        if text.is_empty() {
+            self.items.pop();
+        }
+        Some(base)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_kanji_ruby() {
+        let mut builder = LineItemBuilder::new();
+        builder.push_text("吾輩は猫".to_string());
+        assert!(builder.push_kanji_ruby("ねこ".to_string()));
+        builder.push_text("である".to_string());
+        assert!(!builder.push_kanji_ruby("だ".to_string()));
+        assert_eq!(
+            builder.build(),
+            vec![
+                LineItem::Text("吾輩は".to_string()),
+                LineItem::TextWithRuby(("猫".to_string(), "ねこ".to_string())),
+                LineItem::Text("である".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_push_text_after_exclamation() {
+        let mut builder = LineItemBuilder::new();
+        builder.push_text("にゃあ".to_string());
+        builder.push_terminator("！");
+        builder.push_text("　にゃあ".to_string());
+        builder.push_ruby("まだ".to_string(), "・・".to_string());
+        assert_eq!(
+            builder.build(),
+            vec![
+                LineItem::Text("にゃあ".to_string()),
+                LineItem::EndOfSentence(Terminator::Exclamation("！".to_string())),
+                LineItem::Text("にゃあ".to_string()),
+                LineItem::TextWithSesame(("まだ".to_string(), '・')),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_push_ruby_merges_sesame() {
+        let mut builder = LineItemBuilder::new();
+        builder.push_ruby("ま".to_string(), "・".to_string());
+        builder.push_ruby("だ".to_string(), "﹅".to_string());
+        builder.push_ruby("猫".to_string(), "ねこ".to_string());
+        assert_eq!(
+            builder.build(),
+            vec![
+                LineItem::TextWithSesame(("まだ".to_string(), '・')),
+                LineItem::TextWithRuby(("猫".to_string(), "ねこ".to_string())),
+            ]
+        );
+    }
+}
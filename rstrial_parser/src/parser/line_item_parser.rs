@@ -1,4 +1,4 @@
-use logos::Lexer;
+use logos::{Lexer, Logos};
 
 use crate::tokens::{line_item::Terminator, LineItem};
 
@@ -7,6 +7,21 @@ use super::terminator_parser::TerminatorParser;
 pub struct LineItemParser;
 
 impl LineItemParser {
+    /// Lexes text from foreign sources leniently.
+    /// Characters the lexer does not accept, such as a stray `}`, are kept as text instead of failing.
+    pub fn parse_lossy(line: &str) -> Vec<LineItem> {
+        let mut lexer = LineItem::lexer(line);
+        let mut items: Vec<LineItem> = vec![];
+        while let Some(item) = lexer.next() {
+            let item = item.unwrap_or_else(|_| LineItem::Text(lexer.slice().to_string()));
+            match (items.last_mut(), item) {
+                (Some(LineItem::Text(last)), LineItem::Text(text)) => last.push_str(&text),
+                (_, item) => items.push(item),
+            }
+        }
+        items
+    }
+
     pub fn to_string(lex: &Lexer<LineItem>) -> String {
         lex.slice().to_owned()
     }
@@ -45,3 +60,19 @@ impl LineItemParser {
             .map(|(a, _)| (a.to_string(), '・'.to_owned()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lossy() {
+        assert_eq!(
+            LineItemParser::parse_lossy("猫 } 犬{author}。"),
+            vec![
+                LineItem::Text("猫 } 犬{author}".to_string()),
+                LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+            ]
+        );
+    }
+}
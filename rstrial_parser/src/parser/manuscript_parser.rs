@@ -1,12 +1,9 @@
 use log::trace;
 use std::str::Lines;
 
-use crate::tokens::{
-    section::{Document, Section},
-    Line,
-};
+use crate::tokens::{section::Section, tag::Tag, Line};
 
-use super::section_parser::SectionParser;
+use super::{info_string_parser::InfoStringParser, section_parser::SectionParser};
 
 #[derive(Debug)]
 pub struct ManuscriptParser<'a> {
@@ -15,12 +12,18 @@ pub struct ManuscriptParser<'a> {
     lines: Box<Lines<'a>>,
     state: State,
     text_buffer: Vec<String>,
-    tags_buffer: Vec<String>,
+    tags_buffer: Vec<Tag>,
+    heading_tags: Vec<Tag>,
+    characters_buffer: Vec<String>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
 enum State {
     Line,
+    // Lines right after a heading, blank lines included. `@tags` here are inherited by the scenes under the heading.
+    Heading,
+    // `@tags` lines under a heading. A blank line ends them, and later `@tags` belong to the next scene only.
+    HeadingTags,
     MultiLine,
 }
 
@@ -32,9 +35,21 @@ impl<'a> ManuscriptParser<'a> {
             lines: Box::new(section.lines()),
             text_buffer: vec![],
             tags_buffer: vec![],
+            heading_tags: vec![],
+            characters_buffer: vec![],
             scene: None,
         }
     }
+
+    fn scene_tags(&self) -> Vec<Tag> {
+        let mut tags: Vec<Tag> = vec![];
+        for tag in self.heading_tags.iter().chain(self.tags_buffer.iter()) {
+            if !tags.contains(tag) {
+                tags.push(tag.clone());
+            }
+        }
+        tags
+    }
 }
 
 impl<'a> Iterator for ManuscriptParser<'a> {
@@ -44,31 +59,56 @@ impl<'a> Iterator for ManuscriptParser<'a> {
         let token = if let Some(line) = self.lines.next() {
             trace!("manuscript: {:?}, character: {:?}", self, line);
             match &self.state {
-                State::Line => match line {
+                State::Line | State::Heading | State::HeadingTags => match line {
                     line if line.starts_with("# ") => {
+                        self.state = State::Heading;
+                        self.heading_tags.clear();
                         let title = line.strip_prefix("# ").unwrap().to_string();
                         Some(Section::Title(title))
                     }
                     line if line.starts_with("```") => {
                         self.state = State::MultiLine;
-                        let title = line.strip_prefix("```").unwrap().to_string();
-                        self.scene = Some(Section::Scene(
-                            Document::new(title, None, self.tags_buffer.clone()),
-                            vec![],
-                        ));
+                        let info = line.strip_prefix("```").unwrap();
+                        let mut document = InfoStringParser::new(info).parse();
+                        document.tags = self.scene_tags();
+                        document.inherited_tags = self.heading_tags.clone();
+                        document.characters = std::mem::take(&mut self.characters_buffer);
+                        self.scene = Some(Section::Scene(document, vec![]));
                         self.tags_buffer.clear();
                         self.next()
                     }
                     line if line.starts_with("@tags") => {
-                        let tags = line
-                            .strip_prefix("@tags ")?
-                            .split('/')
-                            .map(|tag| tag.to_string())
-                            .collect::<Vec<String>>();
-                        self.tags_buffer.extend(tags);
+                        let tags = Tag::parse_list(line.strip_prefix("@tags").unwrap());
+                        match self.state {
+                            State::Heading | State::HeadingTags => {
+                                self.state = State::HeadingTags;
+                                self.heading_tags.extend(tags);
+                            }
+                            _ => self.tags_buffer.extend(tags),
+                        }
+                        self.next()
+                    }
+                    // Speakers of the next scene, separated by whitespaces or `/` as tags are.
+                    line if line.starts_with("@characters") => {
+                        let characters = line.strip_prefix("@characters").unwrap();
+                        self.characters_buffer.extend(
+                            characters
+                                .split(|c: char| c.is_whitespace() || c == '/')
+                                .filter(|name| !name.is_empty())
+                                .map(|name| name.to_string()),
+                        );
+                        self.next()
+                    }
+                    line if line.trim().is_empty() => {
+                        if self.state == State::HeadingTags {
+                            self.state = State::Line;
+                        }
+                        self.next()
+                    }
+                    _ => {
+                        self.state = State::Line;
                         self.next()
                     }
-                    _ => self.next(),
                 },
                 State::MultiLine => match line {
                     line if line.starts_with("```") => {
@@ -100,7 +140,7 @@ impl<'a> Iterator for ManuscriptParser<'a> {
 #[cfg(test)]
 mod tests {
 
-    use crate::tokens::{line_item::Terminator, LineItem};
+    use crate::tokens::{line_item::Terminator, section::Document, LineItem};
 
     use super::*;
 
@@ -112,7 +152,7 @@ mod tests {
                     vec![
                         Section::Title("タイトル".to_string()),
                         Section::Scene(
-                            Document::new("第一シーン".to_string(), None, vec!["猫".to_string(), "夏目漱石".to_string()]),
+                            Document::new("第一シーン".to_string(), None, vec![Tag::new("猫"), Tag::new("夏目漱石")]),
                             vec![
                                 Line::Paragraph(vec![
                                     LineItem::Text("吾輩は".to_string()),
@@ -148,4 +188,58 @@ mod tests {
             assert_eq!(actual, expected);
         }
     }
+
+    #[test]
+    fn test_parse_inherited_tags() {
+        let input = "# 第一章\n\n@tags 猫 thread:romance\n\n@tags pov:猫\n```第一シーン\n```\n@tags mood:tense/猫\n```第二シーン\n```\n# 第二章\n```第三シーン\n```\n";
+        let actual = ManuscriptParser::new(input).collect::<Vec<Section>>();
+        let inherited_tags = vec![Tag::new("猫"), Tag::new("thread:romance")];
+        let mut first = Document::new(
+            "第一シーン".to_string(),
+            None,
+            vec![
+                Tag::new("猫"),
+                Tag::new("thread:romance"),
+                Tag::new("pov:猫"),
+            ],
+        );
+        first.inherited_tags = inherited_tags.clone();
+        let mut second = Document::new(
+            "第二シーン".to_string(),
+            None,
+            vec![
+                Tag::new("猫"),
+                Tag::new("thread:romance"),
+                Tag::new("mood:tense"),
+            ],
+        );
+        second.inherited_tags = inherited_tags;
+        let expected = vec![
+            Section::Title("第一章".to_string()),
+            Section::Scene(first, vec![]),
+            Section::Scene(second, vec![]),
+            Section::Title("第二章".to_string()),
+            Section::Scene(
+                Document::new("第三シーン".to_string(), None, vec![]),
+                vec![],
+            ),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_characters() {
+        let input = "@characters 太郎 花子/猫\n```第一シーン\n```\n```第二シーン\n```\n";
+        let actual = ManuscriptParser::new(input).collect::<Vec<Section>>();
+        let mut document = Document::new("第一シーン".to_string(), None, vec![]);
+        document.characters = vec!["太郎".to_string(), "花子".to_string(), "猫".to_string()];
+        let expected = vec![
+            Section::Scene(document, vec![]),
+            Section::Scene(
+                Document::new("第二シーン".to_string(), None, vec![]),
+                vec![],
+            ),
+        ];
+        assert_eq!(actual, expected);
+    }
 }
@@ -1,5 +1,6 @@
 use std::str::Lines;
 
+use log::warn;
 use logos::Logos;
 
 use crate::tokens::{Line, LineItem};
@@ -7,6 +8,8 @@ use crate::tokens::{Line, LineItem};
 pub struct SectionParser<'a> {
     pub source: String,
     lines: Box<Lines<'a>>,
+    // Text after the `*/` closing a block comment on the same line.
+    rest: Option<&'a str>,
 }
 
 impl<'a> SectionParser<'a> {
@@ -14,21 +17,84 @@ impl<'a> SectionParser<'a> {
         Self {
             source: section.to_string(),
             lines: Box::new(section.lines()),
+            rest: None,
         }
     }
 }
 
+impl<'a> SectionParser<'a> {
+    // The comment ends at the first `*/`, and the text after it is parsed as the next line.
+    fn block_comment(&mut self, first_line: &'a str) -> String {
+        let mut lines = vec![];
+        let mut line = first_line.strip_prefix("/*").unwrap();
+        loop {
+            if let Some((comment, rest)) = line.split_once("*/") {
+                lines.push(comment);
+                let rest = rest.trim_start();
+                if !rest.is_empty() {
+                    self.rest = Some(rest);
+                }
+                break;
+            }
+            lines.push(line);
+            match self.lines.next() {
+                Some(next) => line = next,
+                None => {
+                    warn!("block comment is not closed with */: {}", first_line);
+                    break;
+                }
+            }
+        }
+        lines.join("\n")
+    }
+
+    // Regions may nest, so the region ends at the `:::` matching its own `:::author`.
+    fn author_only(&mut self) -> Vec<Line> {
+        let mut lines = vec![];
+        let mut depth = 1;
+        for line in self.lines.by_ref() {
+            match line.trim_end() {
+                ":::author" => depth += 1,
+                ":::" => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 {
+                break;
+            }
+            lines.push(line);
+        }
+        let body = lines.join("\n");
+        SectionParser::new(body.as_str()).collect()
+    }
+}
+
+// Lines in `「」`, which may be preceded by a speaker marker such as `{#@太郎}「……」`.
+fn is_conversation(line: &str) -> bool {
+    let line = match line.strip_prefix("{#@") {
+        Some(rest) => rest.split_once('}').map_or(rest, |(_, rest)| rest),
+        None => line,
+    };
+    line.starts_with('「')
+}
+
 impl<'a> Iterator for SectionParser<'a> {
     type Item = Line;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(line_str) = self.lines.next() {
+        if let Some(line_str) = self.rest.take().or_else(|| self.lines.next()) {
             let line_parser = LineItem::lexer(line_str);
-            if line_str.starts_with("//") {
+            if line_str.starts_with("/*") {
+                Some(Line::BlockComment(self.block_comment(line_str)))
+            } else if line_str.trim_end() == ":::author" {
+                Some(Line::AuthorOnly(self.author_only()))
+            } else if line_str.starts_with("//") {
                 Some(Line::Comment(
-                    line_str.strip_prefix("//").unwrap_or_else(|| panic!("parsing failed: {}", line_str)).to_string(),
+                    line_str
+                        .strip_prefix("//")
+                        .unwrap_or_else(|| panic!("parsing failed: {}", line_str))
+                        .to_string(),
                 ))
-            } else if line_str.starts_with('「') {
+            } else if is_conversation(line_str) {
                 let items: Vec<LineItem> = line_parser
                     .map(|item| item.unwrap_or_else(|_| panic!("parsing failed: {}", line_str)))
                     .collect::<Vec<LineItem>>();
@@ -75,4 +141,92 @@ mod tests {
         ];
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_parse_blocks() {
+        let section =
+            "/* 没にした書き出し\n吾輩は犬である。*/\n:::author\n// 別案\n名前はポチ。\n:::\n/**/";
+        let section_parser = SectionParser::new(section);
+        let actual = section_parser.collect::<Vec<Line>>();
+        let expected = vec![
+            Line::BlockComment(" 没にした書き出し\n吾輩は犬である。".to_string()),
+            Line::AuthorOnly(vec![
+                Line::Comment(" 別案".to_string()),
+                Line::Paragraph(vec![
+                    LineItem::Text("名前はポチ".to_string()),
+                    LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+                ]),
+            ]),
+            Line::BlockComment("".to_string()),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_speaker_marker() {
+        let actual = SectionParser::new("{#@太郎}「にゃあ」").collect::<Vec<Line>>();
+        let expected = vec![Line::Conversation(vec![
+            LineItem::Comment("@太郎".to_string()),
+            LineItem::Text("「にゃあ".to_string()),
+            LineItem::EndOfSentence(Terminator::Normal("」".to_string())),
+        ])];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_nested_author_only() {
+        let section = "本文。\n:::author\n外側\n:::author\n内側\n:::\n秘密の続き。\n:::\n続き。";
+        let actual = SectionParser::new(section).collect::<Vec<Line>>();
+        let expected = vec![
+            Line::Paragraph(vec![
+                LineItem::Text("本文".to_string()),
+                LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+            ]),
+            Line::AuthorOnly(vec![
+                Line::Paragraph(vec![LineItem::Text("外側".to_string())]),
+                Line::AuthorOnly(vec![Line::Paragraph(vec![LineItem::Text(
+                    "内側".to_string(),
+                )])]),
+                Line::Paragraph(vec![
+                    LineItem::Text("秘密の続き".to_string()),
+                    LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+                ]),
+            ]),
+            Line::Paragraph(vec![
+                LineItem::Text("続き".to_string()),
+                LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+            ]),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_inline_block_comment() {
+        let actual = SectionParser::new("/* メモ */ 本文。\n続き。").collect::<Vec<Line>>();
+        let expected = vec![
+            Line::BlockComment(" メモ ".to_string()),
+            Line::Paragraph(vec![
+                LineItem::Text("本文".to_string()),
+                LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+            ]),
+            Line::Paragraph(vec![
+                LineItem::Text("続き".to_string()),
+                LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+            ]),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_unterminated_block_comment() {
+        let actual = SectionParser::new("本文。\n/* 閉じ忘れ\n続き。").collect::<Vec<Line>>();
+        let expected = vec![
+            Line::Paragraph(vec![
+                LineItem::Text("本文".to_string()),
+                LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+            ]),
+            Line::BlockComment(" 閉じ忘れ\n続き。".to_string()),
+        ];
+        assert_eq!(actual, expected);
+    }
 }
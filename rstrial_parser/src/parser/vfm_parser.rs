@@ -0,0 +1,207 @@
+use std::{collections::VecDeque, iter::Peekable, str::Lines};
+
+use log::trace;
+
+use crate::tokens::{
+    section::{Document, Section},
+    Line, LineItem,
+};
+
+use super::{line_item_builder::SESAME_MARKS, line_item_parser::LineItemParser};
+
+/// Parser for Vivliostyle Flavored Markdown such as the output of `VfmManuscriptConverter`.
+///
+/// `# ` headings become titles and lower level headings start a new scene.
+/// Scenes are also separated by `†` and thematic breaks, and consecutive lines are joined into a paragraph.
+#[derive(Debug)]
+pub struct VfmParser<'a> {
+    lines: Peekable<Lines<'a>>,
+    started: bool,
+    finished: bool,
+    scene_title: String,
+    paragraph_buffer: Vec<&'a str>,
+    body_buffer: Vec<Line>,
+    sections: VecDeque<Section>,
+}
+
+impl<'a> VfmParser<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            lines: source.lines().peekable(),
+            started: false,
+            finished: false,
+            scene_title: String::new(),
+            paragraph_buffer: vec![],
+            body_buffer: vec![],
+            sections: VecDeque::new(),
+        }
+    }
+
+    fn skip_front_matter(&mut self) {
+        self.started = true;
+        if self
+            .lines
+            .next_if(|line| line.trim_end() == "---")
+            .is_some()
+        {
+            for line in self.lines.by_ref() {
+                if line.trim_end() == "---" {
+                    break;
+                }
+            }
+        }
+    }
+
+    fn flush_paragraph(&mut self) {
+        if self.paragraph_buffer.is_empty() {
+            return;
+        }
+        let paragraph = std::mem::take(&mut self.paragraph_buffer).concat();
+        self.body_buffer.push(Self::parse_paragraph(&paragraph));
+    }
+
+    fn flush_scene(&mut self) {
+        self.flush_paragraph();
+        let body = std::mem::take(&mut self.body_buffer);
+        let title = std::mem::take(&mut self.scene_title);
+        if !body.is_empty() || !title.is_empty() {
+            self.sections
+                .push_back(Section::Scene(Document::new(title, None, vec![]), body));
+        }
+    }
+
+    fn parse_paragraph(paragraph: &str) -> Line {
+        if let Some(comment) = paragraph
+            .strip_prefix("<!--")
+            .and_then(|comment| comment.strip_suffix("-->"))
+        {
+            return Line::Comment(comment.to_string());
+        }
+        if let Some(quotation) = paragraph.strip_prefix('>') {
+            return Line::Quotation(Self::parse_items(quotation.trim_start()));
+        }
+        let paragraph = paragraph.trim_start_matches([' ', '　']);
+        let items = Self::parse_items(paragraph);
+        match paragraph.starts_with('「') {
+            true => Line::Conversation(items),
+            false => Line::Paragraph(items),
+        }
+    }
+
+    fn parse_items(paragraph: &str) -> Vec<LineItem> {
+        // Markdown written by hand may have stray braces, which are kept as text.
+        LineItemParser::parse_lossy(paragraph)
+            .into_iter()
+            .map(|item| match item {
+                LineItem::TextWithRuby((text, ruby))
+                    if ruby.chars().all(|c| SESAME_MARKS.contains(&c)) =>
+                {
+                    LineItem::TextWithSesame((text, '・'))
+                }
+                item => item,
+            })
+            .collect()
+    }
+
+    fn is_scene_separator(line: &str) -> bool {
+        let line: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+        line == "†"
+            || (line.len() >= 3
+                && ['-', '*', '_']
+                    .iter()
+                    .any(|mark| line.chars().all(|c| c == *mark)))
+    }
+}
+
+impl<'a> Iterator for VfmParser<'a> {
+    type Item = Section;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.skip_front_matter();
+        }
+        while self.sections.is_empty() && !self.finished {
+            let Some(line) = self.lines.next() else {
+                self.finished = true;
+                self.flush_scene();
+                break;
+            };
+            trace!("vfm: {:?}", line);
+            match line {
+                line if line.starts_with("# ") => {
+                    self.flush_scene();
+                    let title = line.strip_prefix("# ").unwrap().trim().to_string();
+                    self.sections.push_back(Section::Title(title));
+                }
+                line if line.starts_with("##") && line.trim_start_matches('#').starts_with(' ') => {
+                    self.flush_scene();
+                    self.scene_title = line.trim_start_matches('#').trim().to_string();
+                }
+                line if Self::is_scene_separator(line) => self.flush_scene(),
+                line if line.trim_matches([' ', '\t']).is_empty() => self.flush_paragraph(),
+                _ => self.paragraph_buffer.push(line),
+            }
+        }
+        let section = self.sections.pop_front();
+        trace!("parse: {:?}", section);
+        section
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tokens::line_item::Terminator;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let input = "---\ntitle: 吾輩は猫である\n---\n# 表題\n\n\n　{吾輩|わがはい}は猫である。\n名前は{まだ|・・・・・・}無い。\n\n<!-- 猫でなく犬にすることも検討 -->\n\n　\n\n†\n\n## 第2シーン\n\n> 「どうも」\n\n 「にゃあ」\n\n";
+        let actual = VfmParser::new(input).collect::<Vec<Section>>();
+        let expected = vec![
+            Section::Title("表題".to_string()),
+            Section::Scene(
+                Document::new("".to_string(), None, vec![]),
+                vec![
+                    Line::Paragraph(vec![
+                        LineItem::TextWithRuby(("吾輩".to_string(), "わがはい".to_string())),
+                        LineItem::Text("は猫である".to_string()),
+                        LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+                        LineItem::Text("名前は".to_string()),
+                        LineItem::TextWithSesame(("まだ".to_string(), '・')),
+                        LineItem::Text("無い".to_string()),
+                        LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+                    ]),
+                    Line::Comment(" 猫でなく犬にすることも検討 ".to_string()),
+                    Line::Paragraph(vec![]),
+                ],
+            ),
+            Section::Scene(
+                Document::new("第2シーン".to_string(), None, vec![]),
+                vec![
+                    Line::Quotation(vec![
+                        LineItem::Text("「どうも".to_string()),
+                        LineItem::EndOfSentence(Terminator::Normal("」".to_string())),
+                    ]),
+                    Line::Conversation(vec![
+                        LineItem::Text("「にゃあ".to_string()),
+                        LineItem::EndOfSentence(Terminator::Normal("」".to_string())),
+                    ]),
+                ],
+            ),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_stray_braces() {
+        let actual = VfmParser::new("猫 } 犬 {\n").collect::<Vec<Section>>();
+        let expected = vec![Section::Scene(
+            Document::new("".to_string(), None, vec![]),
+            vec![Line::Paragraph(vec![LineItem::Text(
+                "猫 } 犬 {".to_string(),
+            )])],
+        )];
+        assert_eq!(actual, expected);
+    }
+}
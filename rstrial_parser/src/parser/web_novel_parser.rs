@@ -0,0 +1,324 @@
+use std::{collections::VecDeque, str::Lines};
+
+use log::trace;
+use logos::Logos;
+
+use crate::tokens::{
+    section::{Document, Section},
+    Line, LineItem,
+};
+
+use super::line_item_builder::LineItemBuilder;
+
+/// Narou shows ruby as it is if the base text or the ruby is longer than this, whichever notation is used.
+pub const NAROU_MAX_RUBY_LENGTH: usize = 10;
+
+/// Web novel platforms which `WebNovelParser` understands.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Platform {
+    /// Kakuyomu(カクヨム)
+    Kakuyomu,
+    /// Shosetsuka ni Naro(小説家になろう)
+    Narou,
+}
+
+// Tokens for a line written in web novel notations.
+#[derive(Logos, Debug, PartialEq, Clone)]
+enum WebNovelToken {
+    // Plaintext to be rendered as-is.
+    #[regex(r"[^|｜《（(,、，!?！？。」]+", priority = 0, callback = |lex| lex.slice().to_owned())]
+    Text(String),
+    // A ruby marker which is not a part of any notation.
+    #[regex(r"[|｜《（(]", priority = 0, callback = |lex| lex.slice().to_owned())]
+    Stray(String),
+    // A bracket escaped by `|` such as `|《`.
+    #[regex(r"[|｜][《（(]", |lex| lex.slice().chars().skip(1).collect::<String>())]
+    Escaped(String),
+    // Ruby with an explicit base text such as `|漢字《かんじ》`.
+    #[regex(r"[|｜][^|｜《》（）()]+《[^》]+》", |lex| lex.slice().to_owned())]
+    RubyWithBase(String),
+    // Ruby in parentheses with an explicit base text such as `|漢字(かんじ)`. Narou only.
+    #[regex(r"[|｜][^|｜《》（）()]+[（(][^）)]+[）)]", |lex| lex.slice().to_owned())]
+    ParenthesizedRubyWithBase(String),
+    // Emphasis such as `《《傍点》》`. Kakuyomu only.
+    #[regex(r"《《[^》]+》》", |lex| lex.slice().to_owned())]
+    Emphasis(String),
+    // Ruby for the preceding run of kanji such as `漢字《かんじ》`.
+    #[regex(r"《[^》]+》", |lex| lex.slice().to_owned())]
+    Ruby(String),
+    // Ruby in parentheses for the preceding run of kanji such as `漢字(かんじ)`. Narou only.
+    #[regex(r"[（(][ぁ-ゖァ-ヺー]+[）)]", |lex| lex.slice().to_owned())]
+    ParenthesizedRuby(String),
+    // A Sentence delimiter such as `,` or `、`.
+    #[regex(r"[,、，]", |lex| lex.slice().to_owned())]
+    Comma(String),
+    // End of sentence.
+    #[regex(r"[!?！？。」]+", |lex| lex.slice().to_owned())]
+    EndOfSentence(String),
+}
+
+/// Parser for texts written in the notations of Kakuyomu or Shosetsuka ni Naro.
+///
+/// Neither platform exports headings along with episodes, so titles and episodes are marked by hand
+/// with the generic `# ` and `## ` headings: `# ` headings become titles and `## ` headings
+/// start a new episode, which is parsed as a scene. A text without headings is parsed as a single episode.
+#[derive(Debug)]
+pub struct WebNovelParser<'a> {
+    platform: Platform,
+    lines: Lines<'a>,
+    finished: bool,
+    scene_title: String,
+    text_buffer: Vec<&'a str>,
+    sections: VecDeque<Section>,
+}
+
+impl<'a> WebNovelParser<'a> {
+    pub fn new(source: &'a str, platform: Platform) -> Self {
+        Self {
+            platform,
+            lines: source.lines(),
+            finished: false,
+            scene_title: String::new(),
+            text_buffer: vec![],
+            sections: VecDeque::new(),
+        }
+    }
+
+    fn flush_scene(&mut self) {
+        let text_buffer = std::mem::take(&mut self.text_buffer);
+        let title = std::mem::take(&mut self.scene_title);
+        let start = text_buffer.iter().position(|line| !line.trim().is_empty());
+        let end = text_buffer.iter().rposition(|line| !line.trim().is_empty());
+        let body: Vec<Line> = match (start, end) {
+            (Some(start), Some(end)) => text_buffer[start..=end]
+                .iter()
+                .map(|line| self.parse_line(line))
+                .collect(),
+            _ => vec![],
+        };
+        if !body.is_empty() || !title.is_empty() {
+            self.sections
+                .push_back(Section::Scene(Document::new(title, None, vec![]), body));
+        }
+    }
+
+    fn parse_line(&self, line: &str) -> Line {
+        let line = line.trim_start_matches([' ', '　']);
+        let items = self.parse_items(line);
+        match line.starts_with('「') {
+            true => Line::Conversation(items),
+            false => Line::Paragraph(items),
+        }
+    }
+
+    fn parse_items(&self, line: &str) -> Vec<LineItem> {
+        let mut builder = LineItemBuilder::new();
+        for token in WebNovelToken::lexer(line) {
+            let token = token.unwrap_or_else(|_| panic!("parsing failed: {}", line));
+            match (token, self.platform) {
+                (WebNovelToken::Text(text) | WebNovelToken::Stray(text), _) => {
+                    builder.push_text(text)
+                }
+                (WebNovelToken::Escaped(bracket), _) => builder.push_text(bracket),
+                (WebNovelToken::RubyWithBase(ruby), _)
+                | (WebNovelToken::ParenthesizedRubyWithBase(ruby), Platform::Narou) => {
+                    match Self::split_ruby(&ruby) {
+                        Some((base, reading)) if self.is_within_limits(&base, &reading) => {
+                            builder.push_ruby(base, reading)
+                        }
+                        _ => builder.push_text(ruby),
+                    }
+                }
+                (WebNovelToken::Emphasis(emphasis), Platform::Kakuyomu) => {
+                    let text = emphasis.trim_start_matches('《').trim_end_matches('》');
+                    builder.push(LineItem::TextWithSesame((text.to_string(), '・')));
+                }
+                (WebNovelToken::Ruby(ruby), _) => {
+                    let reading = ruby.trim_start_matches('《').trim_end_matches('》');
+                    if !self.is_within_limits("", reading)
+                        || !builder.push_kanji_ruby(reading.to_string())
+                    {
+                        builder.push_text(ruby);
+                    }
+                }
+                (WebNovelToken::ParenthesizedRuby(ruby), Platform::Narou) => {
+                    let reading = ruby
+                        .trim_start_matches(['(', '（'])
+                        .trim_end_matches([')', '）']);
+                    if !self.is_within_limits("", reading)
+                        || !builder.push_kanji_ruby(reading.to_string())
+                    {
+                        builder.push_text(ruby);
+                    }
+                }
+                (
+                    WebNovelToken::ParenthesizedRubyWithBase(text)
+                    | WebNovelToken::Emphasis(text)
+                    | WebNovelToken::ParenthesizedRuby(text),
+                    _,
+                ) => builder.push_text(text),
+                (WebNovelToken::Comma(comma), _) => builder.push(LineItem::Comma(comma)),
+                (WebNovelToken::EndOfSentence(terminator), _) => {
+                    builder.push_terminator(&terminator)
+                }
+            }
+        }
+        builder.build()
+    }
+
+    // Ruby beyond the limits of the platform is not ruby but text there.
+    fn is_within_limits(&self, base: &str, ruby: &str) -> bool {
+        match self.platform {
+            Platform::Kakuyomu => true,
+            Platform::Narou => {
+                base.chars().count() <= NAROU_MAX_RUBY_LENGTH
+                    && ruby.chars().count() <= NAROU_MAX_RUBY_LENGTH
+            }
+        }
+    }
+
+    fn split_ruby(ruby: &str) -> Option<(String, String)> {
+        ruby.trim_start_matches(['|', '｜'])
+            .strip_suffix(['》', ')', '）'])?
+            .split_once(['《', '(', '（'])
+            .map(|(base, ruby)| (base.to_string(), ruby.to_string()))
+    }
+}
+
+impl<'a> Iterator for WebNovelParser<'a> {
+    type Item = Section;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.sections.is_empty() && !self.finished {
+            let Some(line) = self.lines.next() else {
+                self.finished = true;
+                self.flush_scene();
+                break;
+            };
+            trace!("web novel: {:?}", line);
+            match line {
+                line if line.starts_with("# ") => {
+                    self.flush_scene();
+                    let title = line.strip_prefix("# ").unwrap().trim().to_string();
+                    self.sections.push_back(Section::Title(title));
+                }
+                line if line.starts_with("## ") => {
+                    self.flush_scene();
+                    self.scene_title = line.strip_prefix("## ").unwrap().trim().to_string();
+                }
+                _ => self.text_buffer.push(line),
+            }
+        }
+        let section = self.sections.pop_front();
+        trace!("parse: {:?}", section);
+        section
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tokens::line_item::Terminator;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_kakuyomu() {
+        let input = "# 吾輩は猫である\n\n## 第一話\n\n　吾輩《わがはい》は|猫《ねこ》である。名前は《《まだ》》無い|《仮》！　\n\n## 第二話\n「見当(けんとう)がつかぬ」\n";
+        let actual = WebNovelParser::new(input, Platform::Kakuyomu).collect::<Vec<Section>>();
+        let expected = vec![
+            Section::Title("吾輩は猫である".to_string()),
+            Section::Scene(
+                Document::new("第一話".to_string(), None, vec![]),
+                vec![Line::Paragraph(vec![
+                    LineItem::TextWithRuby(("吾輩".to_string(), "わがはい".to_string())),
+                    LineItem::Text("は".to_string()),
+                    LineItem::TextWithRuby(("猫".to_string(), "ねこ".to_string())),
+                    LineItem::Text("である".to_string()),
+                    LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+                    LineItem::Text("名前は".to_string()),
+                    LineItem::TextWithSesame(("まだ".to_string(), '・')),
+                    LineItem::Text("無い《仮》".to_string()),
+                    LineItem::EndOfSentence(Terminator::Exclamation("！".to_string())),
+                ])],
+            ),
+            Section::Scene(
+                Document::new("第二話".to_string(), None, vec![]),
+                vec![Line::Conversation(vec![
+                    LineItem::Text("「見当(けんとう)がつかぬ".to_string()),
+                    LineItem::EndOfSentence(Terminator::Normal("」".to_string())),
+                ])],
+            ),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_narou() {
+        let input = "「見当(けんとう)がつかぬ」\n|吾輩（わがはい）は|猫《・》、《《まだ》》\n";
+        let actual = WebNovelParser::new(input, Platform::Narou).collect::<Vec<Section>>();
+        let expected = vec![Section::Scene(
+            Document::new("".to_string(), None, vec![]),
+            vec![
+                Line::Conversation(vec![
+                    LineItem::Text("「".to_string()),
+                    LineItem::TextWithRuby(("見当".to_string(), "けんとう".to_string())),
+                    LineItem::Text("がつかぬ".to_string()),
+                    LineItem::EndOfSentence(Terminator::Normal("」".to_string())),
+                ]),
+                Line::Paragraph(vec![
+                    LineItem::TextWithRuby(("吾輩".to_string(), "わがはい".to_string())),
+                    LineItem::Text("は".to_string()),
+                    LineItem::TextWithSesame(("猫".to_string(), '・')),
+                    LineItem::Comma("、".to_string()),
+                    LineItem::Text("《《まだ》》".to_string()),
+                ]),
+            ],
+        )];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_sesame_per_character() {
+        let input = "名前は|ま《・》|だ《・》無い。\n";
+        let actual = WebNovelParser::new(input, Platform::Narou).collect::<Vec<Section>>();
+        let expected = vec![Section::Scene(
+            Document::new("".to_string(), None, vec![]),
+            vec![Line::Paragraph(vec![
+                LineItem::Text("名前は".to_string()),
+                LineItem::TextWithSesame(("まだ".to_string(), '・')),
+                LineItem::Text("無い".to_string()),
+                LineItem::EndOfSentence(Terminator::Normal("。".to_string())),
+            ])],
+        )];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_narou_long_ruby() {
+        let input = "|長い名前《じゅういちもじのるびです》と|猫《ねこ》\n";
+        let actual = WebNovelParser::new(input, Platform::Narou).collect::<Vec<Section>>();
+        let expected = vec![Section::Scene(
+            Document::new("".to_string(), None, vec![]),
+            vec![Line::Paragraph(vec![
+                LineItem::Text("|長い名前《じゅういちもじのるびです》と".to_string()),
+                LineItem::TextWithRuby(("猫".to_string(), "ねこ".to_string())),
+            ])],
+        )];
+        assert_eq!(actual, expected);
+        let actual = WebNovelParser::new(input, Platform::Kakuyomu).collect::<Vec<Section>>();
+        let Section::Scene(_, body) = &actual[0] else {
+            panic!("not a scene: {:?}", actual);
+        };
+        assert_eq!(
+            body[0],
+            Line::Paragraph(vec![
+                LineItem::TextWithRuby((
+                    "長い名前".to_string(),
+                    "じゅういちもじのるびです".to_string()
+                )),
+                LineItem::Text("と".to_string()),
+                LineItem::TextWithRuby(("猫".to_string(), "ねこ".to_string())),
+            ])
+        );
+    }
+}
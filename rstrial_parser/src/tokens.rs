@@ -1,6 +1,7 @@
 pub mod line;
 pub mod line_item;
 pub mod section;
+pub mod tag;
 
 pub use line::Line;
 pub use line_item::LineItem;
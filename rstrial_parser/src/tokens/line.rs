@@ -6,4 +6,8 @@ pub enum Line {
     Conversation(Vec<LineItem>),
     Quotation(Vec<LineItem>),
     Comment(String),
+    // A comment spanning lines between `/*` and `*/`.
+    BlockComment(String),
+    // Lines between `:::author` and `:::`, such as cut passages and alternate takes.
+    AuthorOnly(Vec<Line>),
 }
@@ -1,4 +1,6 @@
-use super::Line;
+use std::collections::BTreeMap;
+
+use super::{tag::Tag, Line};
 
 type Body = Vec<Line>;
 
@@ -31,10 +33,77 @@ pub enum Section {
 pub struct Document {
     pub title: String,
     pub body: Option<String>,
-    pub tags: Vec<String>,
+    pub tags: Vec<Tag>,
+    // Tags inherited from the heading, which are also included in `tags`.
+    pub inherited_tags: Vec<Tag>,
+    // ID given as `#id` in the attribute list of the info string.
+    pub id: Option<String>,
+    // Attributes given as `key=value` in the attribute list of the info string.
+    pub attributes: BTreeMap<String, String>,
+    // Flags such as `draft` or `skip` in the attribute list of the info string.
+    pub flags: Vec<String>,
+    // Speakers of the scene given by `@characters`.
+    pub characters: Vec<String>,
 }
 impl Document {
-    pub fn new(title: String, body: Option<String>, tags: Vec<String>) -> Self {
-        Self { title, body, tags }
+    pub fn new(title: String, body: Option<String>, tags: Vec<Tag>) -> Self {
+        Self {
+            title,
+            body,
+            tags,
+            inherited_tags: vec![],
+            id: None,
+            attributes: BTreeMap::new(),
+            flags: vec![],
+            characters: vec![],
+        }
+    }
+
+    /// Stable ID of the scene: the given `#id`, or the title with whitespaces replaced by `-`.
+    pub fn scene_id(&self) -> Option<String> {
+        match &self.id {
+            Some(id) => Some(id.clone()),
+            None if self.title.trim().is_empty() => None,
+            None => Some(
+                self.title
+                    .split_whitespace()
+                    .collect::<Vec<&str>>()
+                    .join("-"),
+            ),
+        }
+    }
+
+    pub fn has_flag(&self, flag: &str) -> bool {
+        self.flags.iter().any(|f| f == flag)
+    }
+
+    /// Draft scenes are checked but excluded from converted manuscripts.
+    pub fn is_draft(&self) -> bool {
+        self.has_flag("draft")
+    }
+
+    /// Skipped scenes are excluded from both checking and converted manuscripts.
+    pub fn is_skipped(&self) -> bool {
+        self.has_flag("skip")
+    }
+
+    /// Formats the ID, attributes and flags as an attribute list such as `{#scene-2 pov=美咲 draft}`.
+    /// Returns an empty string if there is nothing to format.
+    pub fn attribute_list(&self) -> String {
+        let mut attributes = vec![];
+        if let Some(id) = &self.id {
+            attributes.push(format!("#{id}"));
+        }
+        attributes.extend(self.attributes.iter().map(|(key, value)| {
+            match value.contains(char::is_whitespace) {
+                true => format!("{key}=\"{value}\""),
+                false => format!("{key}={value}"),
+            }
+        }));
+        attributes.extend(self.flags.iter().cloned());
+        match attributes.is_empty() {
+            true => "".to_string(),
+            false => format!("{{{}}}", attributes.join(" ")),
+        }
     }
 }
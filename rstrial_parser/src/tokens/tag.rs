@@ -0,0 +1,67 @@
+use std::fmt::{Display, Formatter};
+
+/// A tag given by `@tags` such as `猫` or `mood:tense`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Tag {
+    // Namespace before `:`, e.g. `mood` of `mood:tense`.
+    pub namespace: Option<String>,
+    pub name: String,
+}
+
+impl Tag {
+    pub fn new(name: &str) -> Self {
+        match name.split_once(':') {
+            Some((namespace, name)) if !namespace.is_empty() && !name.is_empty() => Self {
+                namespace: Some(namespace.to_string()),
+                name: name.to_string(),
+            },
+            _ => Self {
+                namespace: None,
+                name: name.to_string(),
+            },
+        }
+    }
+
+    /// Parses tags separated by whitespaces or `/` such as `導入/猫 mood:tense`.
+    pub fn parse_list(tags: &str) -> Vec<Tag> {
+        tags.split(|c: char| c.is_whitespace() || c == '/')
+            .filter(|tag| !tag.is_empty())
+            .map(Tag::new)
+            .collect()
+    }
+}
+
+impl Display for Tag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.namespace {
+            Some(namespace) => write!(f, "{}:{}", namespace, self.name),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_list() {
+        let tags = Tag::parse_list("導入/猫 mood:tense　thread:romance");
+        assert_eq!(
+            tags,
+            vec![
+                Tag::new("導入"),
+                Tag::new("猫"),
+                Tag {
+                    namespace: Some("mood".to_string()),
+                    name: "tense".to_string(),
+                },
+                Tag {
+                    namespace: Some("thread".to_string()),
+                    name: "romance".to_string(),
+                },
+            ]
+        );
+        assert_eq!(tags[2].to_string(), "mood:tense");
+    }
+}
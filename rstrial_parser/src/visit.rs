@@ -0,0 +1,280 @@
+//! Traversal of parsed manuscripts.
+//!
+//! [`Visitor`] walks a manuscript by reference, [`VisitorMut`] edits it in place and
+//! [`Fold`] rebuilds it by value. Override only the methods you are interested in;
+//! the default methods call the matching `walk_*` function to descend into children,
+//! so an overriding method can call it too to keep walking.
+
+use crate::tokens::{
+    section::{Document, Section},
+    Line, LineItem,
+};
+
+pub trait Visitor {
+    fn visit_sections(&mut self, sections: &[Section]) {
+        walk_sections(self, sections)
+    }
+
+    fn visit_section(&mut self, section: &Section) {
+        walk_section(self, section)
+    }
+
+    fn visit_title(&mut self, _title: &str) {}
+
+    fn visit_document(&mut self, _document: &Document) {}
+
+    fn visit_line(&mut self, line: &Line) {
+        walk_line(self, line)
+    }
+
+    fn visit_line_item(&mut self, _item: &LineItem) {}
+}
+
+pub fn walk_sections<V: Visitor + ?Sized>(visitor: &mut V, sections: &[Section]) {
+    for section in sections {
+        visitor.visit_section(section);
+    }
+}
+
+pub fn walk_section<V: Visitor + ?Sized>(visitor: &mut V, section: &Section) {
+    match section {
+        Section::Title(title) => visitor.visit_title(title),
+        Section::Scene(document, body) => {
+            visitor.visit_document(document);
+            for line in body {
+                visitor.visit_line(line);
+            }
+        }
+    }
+}
+
+pub fn walk_line<V: Visitor + ?Sized>(visitor: &mut V, line: &Line) {
+    match line {
+        Line::Paragraph(items) | Line::Conversation(items) | Line::Quotation(items) => {
+            for item in items {
+                visitor.visit_line_item(item);
+            }
+        }
+        Line::AuthorOnly(lines) => {
+            for line in lines {
+                visitor.visit_line(line);
+            }
+        }
+        Line::Comment(_) | Line::BlockComment(_) => {}
+    }
+}
+
+pub trait VisitorMut {
+    fn visit_sections_mut(&mut self, sections: &mut [Section]) {
+        walk_sections_mut(self, sections)
+    }
+
+    fn visit_section_mut(&mut self, section: &mut Section) {
+        walk_section_mut(self, section)
+    }
+
+    fn visit_title_mut(&mut self, _title: &mut String) {}
+
+    fn visit_document_mut(&mut self, _document: &mut Document) {}
+
+    fn visit_line_mut(&mut self, line: &mut Line) {
+        walk_line_mut(self, line)
+    }
+
+    fn visit_line_item_mut(&mut self, _item: &mut LineItem) {}
+}
+
+pub fn walk_sections_mut<V: VisitorMut + ?Sized>(visitor: &mut V, sections: &mut [Section]) {
+    for section in sections.iter_mut() {
+        visitor.visit_section_mut(section);
+    }
+}
+
+pub fn walk_section_mut<V: VisitorMut + ?Sized>(visitor: &mut V, section: &mut Section) {
+    match section {
+        Section::Title(title) => visitor.visit_title_mut(title),
+        Section::Scene(document, body) => {
+            visitor.visit_document_mut(document);
+            for line in body.iter_mut() {
+                visitor.visit_line_mut(line);
+            }
+        }
+    }
+}
+
+pub fn walk_line_mut<V: VisitorMut + ?Sized>(visitor: &mut V, line: &mut Line) {
+    match line {
+        Line::Paragraph(items) | Line::Conversation(items) | Line::Quotation(items) => {
+            for item in items.iter_mut() {
+                visitor.visit_line_item_mut(item);
+            }
+        }
+        Line::AuthorOnly(lines) => {
+            for line in lines.iter_mut() {
+                visitor.visit_line_mut(line);
+            }
+        }
+        Line::Comment(_) | Line::BlockComment(_) => {}
+    }
+}
+
+/// Rebuilds a manuscript by value. The `fold_*s` methods take whole lists,
+/// so they can also drop or insert elements.
+pub trait Fold {
+    fn fold_sections(&mut self, sections: Vec<Section>) -> Vec<Section> {
+        fold_sections(self, sections)
+    }
+
+    fn fold_section(&mut self, section: Section) -> Section {
+        fold_section(self, section)
+    }
+
+    fn fold_title(&mut self, title: String) -> String {
+        title
+    }
+
+    fn fold_document(&mut self, document: Document) -> Document {
+        document
+    }
+
+    fn fold_lines(&mut self, lines: Vec<Line>) -> Vec<Line> {
+        fold_lines(self, lines)
+    }
+
+    fn fold_line(&mut self, line: Line) -> Line {
+        fold_line(self, line)
+    }
+
+    fn fold_line_items(&mut self, items: Vec<LineItem>) -> Vec<LineItem> {
+        fold_line_items(self, items)
+    }
+
+    fn fold_line_item(&mut self, item: LineItem) -> LineItem {
+        item
+    }
+}
+
+pub fn fold_sections<F: Fold + ?Sized>(folder: &mut F, sections: Vec<Section>) -> Vec<Section> {
+    sections
+        .into_iter()
+        .map(|section| folder.fold_section(section))
+        .collect()
+}
+
+pub fn fold_section<F: Fold + ?Sized>(folder: &mut F, section: Section) -> Section {
+    match section {
+        Section::Title(title) => Section::Title(folder.fold_title(title)),
+        Section::Scene(document, body) => {
+            Section::Scene(folder.fold_document(document), folder.fold_lines(body))
+        }
+    }
+}
+
+pub fn fold_lines<F: Fold + ?Sized>(folder: &mut F, lines: Vec<Line>) -> Vec<Line> {
+    lines
+        .into_iter()
+        .map(|line| folder.fold_line(line))
+        .collect()
+}
+
+pub fn fold_line<F: Fold + ?Sized>(folder: &mut F, line: Line) -> Line {
+    match line {
+        Line::Paragraph(items) => Line::Paragraph(folder.fold_line_items(items)),
+        Line::Conversation(items) => Line::Conversation(folder.fold_line_items(items)),
+        Line::Quotation(items) => Line::Quotation(folder.fold_line_items(items)),
+        Line::AuthorOnly(lines) => Line::AuthorOnly(folder.fold_lines(lines)),
+        line @ (Line::Comment(_) | Line::BlockComment(_)) => line,
+    }
+}
+
+pub fn fold_line_items<F: Fold + ?Sized>(folder: &mut F, items: Vec<LineItem>) -> Vec<LineItem> {
+    items
+        .into_iter()
+        .map(|item| folder.fold_line_item(item))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ManuscriptParser;
+
+    use super::*;
+
+    const INPUT: &str = "# 表題\n\n```第１シーン\n{吾輩|わがはい}は猫である。\n// メモ\n:::author\n{猫|ねこ}は犬だった。\n:::\n```\n";
+
+    struct RubyCounter(usize);
+
+    impl Visitor for RubyCounter {
+        fn visit_line_item(&mut self, item: &LineItem) {
+            if let LineItem::TextWithRuby(_) = item {
+                self.0 += 1;
+            }
+        }
+    }
+
+    struct RubyStripper;
+
+    impl VisitorMut for RubyStripper {
+        fn visit_line_item_mut(&mut self, item: &mut LineItem) {
+            if let LineItem::TextWithRuby((text, _)) = item {
+                *item = LineItem::Text(text.clone());
+            }
+        }
+    }
+
+    struct CommentRemover;
+
+    impl Fold for CommentRemover {
+        fn fold_lines(&mut self, lines: Vec<Line>) -> Vec<Line> {
+            let lines = fold_lines(self, lines);
+            lines
+                .into_iter()
+                .filter(|line| !matches!(line, Line::Comment(_) | Line::AuthorOnly(_)))
+                .collect()
+        }
+
+        fn fold_line_item(&mut self, item: LineItem) -> LineItem {
+            match item {
+                LineItem::Text(text) => LineItem::Text(text.replace('猫', "犬")),
+                item => item,
+            }
+        }
+    }
+
+    #[test]
+    fn test_visitor() {
+        let sections = ManuscriptParser::new(INPUT).collect::<Vec<Section>>();
+        let mut counter = RubyCounter(0);
+        counter.visit_sections(&sections);
+        assert_eq!(counter.0, 2);
+    }
+
+    #[test]
+    fn test_visitor_mut() {
+        let mut sections = ManuscriptParser::new(INPUT).collect::<Vec<Section>>();
+        RubyStripper.visit_sections_mut(&mut sections);
+        let mut counter = RubyCounter(0);
+        counter.visit_sections(&sections);
+        assert_eq!(counter.0, 0);
+    }
+
+    #[test]
+    fn test_fold() {
+        let sections = ManuscriptParser::new(INPUT).collect::<Vec<Section>>();
+        let sections = CommentRemover.fold_sections(sections);
+        let Section::Scene(_, body) = &sections[1] else {
+            panic!("expected a scene: {:?}", sections[1]);
+        };
+        assert_eq!(body.len(), 1);
+        assert_eq!(
+            body[0],
+            Line::Paragraph(vec![
+                LineItem::TextWithRuby(("吾輩".to_string(), "わがはい".to_string())),
+                LineItem::Text("は犬である".to_string()),
+                LineItem::EndOfSentence(crate::tokens::line_item::Terminator::Normal(
+                    "。".to_string()
+                )),
+            ])
+        );
+    }
+}